@@ -0,0 +1,76 @@
+//! Throughput benchmark for `LogCategorizer::categorize`
+//!
+//! Run with `cargo bench --bench categorizer`. Fails if throughput drops
+//! below `TARGET_MB_PER_SEC`, so a regression in the hot path is caught
+//! before it reaches a multi-GB Client.txt.
+
+#![allow(dead_code, unused_imports)]
+
+#[path = "../src/locale.rs"]
+mod locale;
+
+#[path = "../src/log_categorizer.rs"]
+mod log_categorizer;
+
+use log_categorizer::LogCategorizer;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Minimum acceptable categorization throughput in a release build
+const TARGET_MB_PER_SEC: f64 = 50.0;
+
+const CORPUS_BYTES: usize = 64 * 1024 * 1024;
+
+const SAMPLE_LINES: &[&str] = &[
+    "2025/11/04 19:20:50 187967859 3ef232c2 [INFO Client 7776] $The_Abyss_Guy: BUYING RITUAL PRECUSOR TABLET (all mods except unique) : 2 Tablet = 1 Divine Orbs now)",
+    "2025/11/04 19:22:13 188051531 3ef232c2 [INFO Client 7776] : TomHanksIndexFinger has been slain.",
+    "2025/11/04 19:24:34 188191812 3ef232c2 [INFO Client 7776] : TomHanksIndexFinger (Mercenary) is now level 2",
+    "2025/11/04 19:25:02 188219112 3ef232c2 [INFO Client 7776] #Julekuk: you were wrong",
+    "2025/11/04 19:25:40 188257001 3ef232c2 [INFO Client 7776] &: GUILD UPDATE: Final FTK is done for the season",
+    "2025/11/04 19:26:11 188288310 3ef232c2 [INFO Client 7776] Siora, Blade of the Mists: The King in the Mists claims this wood.",
+    "2025/11/04 19:19:58 187916000 64a60fe5 [WARN Client 7776] Failed to create effect graph node",
+    "2025/11/04 20:55:06 193624140 faf1c1bf [CRIT Client 31400] [RENDER] Shader uses incorrect vertex layout",
+    "2025/11/04 19:20:51 187969250 bf08f15c [INFO Client 7776] [Item Filter] Preparing to download online filter XVEokZIq",
+    "2025/11/04 19:21:03 187981200 3ef232c2 [INFO Client 7776] [SCENE] Set Source [Stones of Serle]",
+    "2025/11/04 19:21:03 187981201 3ef232c2 [INFO Client 7776] Connected to sjc.login.pathofexile2.com in 16ms.",
+    "2025/11/04 19:21:04 187982000 3ef232c2 [DEBUG Client 7776] [ENTITY] Spawned 42 entities in 3ms",
+    "2025/11/04 19:21:05 187983000 3ef232c2 [INFO Client 7776] Successfully allocated passive skill id: projectiles18",
+    "2025/11/04 19:21:06 187984000 3ef232c2 [DEBUG Client 7776] [SOUND] Loaded bank Audio/Music/Act1.bank",
+    "2025/11/04 19:21:07 187985000 3ef232c2 [DEBUG Client 7776] Tile hash 0x1f3a mismatch, regenerating",
+];
+
+fn main() {
+    let categorizer = LogCategorizer::new();
+
+    let mut corpus = Vec::new();
+    let mut total_bytes = 0;
+    while total_bytes < CORPUS_BYTES {
+        for line in SAMPLE_LINES {
+            total_bytes += line.len() + 1;
+            corpus.push(*line);
+        }
+    }
+
+    let start = Instant::now();
+    for line in &corpus {
+        black_box(categorizer.categorize(black_box(line), line));
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+
+    let mb_per_sec = total_bytes as f64 / (1024.0 * 1024.0) / elapsed;
+    let lines_per_sec = corpus.len() as f64 / elapsed;
+    println!(
+        "categorized {} lines ({:.1} MB) in {:.2}s: {:.1} MB/s, {:.0} lines/s (target {:.0} MB/s)",
+        corpus.len(),
+        total_bytes as f64 / (1024.0 * 1024.0),
+        elapsed,
+        mb_per_sec,
+        lines_per_sec,
+        TARGET_MB_PER_SEC
+    );
+
+    if cfg!(not(debug_assertions)) && mb_per_sec < TARGET_MB_PER_SEC {
+        eprintln!("throughput below target of {:.0} MB/s", TARGET_MB_PER_SEC);
+        std::process::exit(1);
+    }
+}
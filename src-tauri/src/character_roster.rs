@@ -0,0 +1,334 @@
+use serde::{Deserialize, Serialize};
+
+pub const CHARACTER_ROSTER_FILE: &str = "characters.json";
+
+/// Classes a character can start as in the English client; other names are ascendancies
+pub const BASE_CLASSES: &[&str] = &[
+    "Warrior", "Ranger", "Witch", "Sorceress", "Mercenary", "Monk", "Huntress", "Druid",
+];
+
+/// A character switching from its base class to an ascendancy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ascension {
+    pub character: String,
+    pub base_class: String,
+    pub ascendancy: String,
+    pub level: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+    pub timestamp: String,
+}
+
+/// Learned base-class to ascendancy pair, e.g. Mercenary -> Witchhunter
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AscendancyMapping {
+    pub base_class: String,
+    pub ascendancy: String,
+}
+
+/// Everything the log has revealed about one of the player's characters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Character {
+    pub name: String,
+    pub class: String,
+    // Starting class, also inferred for characters first seen already ascended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_class: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ascension: Option<Ascension>,
+    pub level: u32,
+    pub deaths: u32,
+    pub first_seen: String,
+    pub last_seen: String,
+    // Client sessions the character was played in
+    pub sessions: u32,
+    // Start of the last counted session, so re-reading a log doesn't count it again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_session_start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_death_at: Option<String>,
+}
+
+/// The player's characters as seen in level-up and death lines, persisted across runs
+/// Party members level up in the same log, so only names marked as the player's own
+/// are persisted; other level-ups are kept for this run so they can still be marked.
+/// Every update is keyed on log timestamps, so replaying a log that was
+/// already read leaves the roster unchanged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CharacterRoster {
+    pub characters: Vec<Character>,
+    #[serde(default)]
+    pub ascendancies: Vec<AscendancyMapping>,
+    #[serde(skip)]
+    others: Vec<Character>,
+    #[serde(skip)]
+    session_start: Option<String>,
+}
+
+impl CharacterRoster {
+    pub fn start_session(&mut self, timestamp: &str) {
+        self.session_start = Some(timestamp.to_string());
+    }
+
+    pub fn is_own(&self, name: &str) -> bool {
+        self.characters.iter().any(|c| c.name == name)
+    }
+
+    /// Level-ups create characters and keep class and level current
+    /// Returns the character, and the ascension when the class changed from a base class
+    /// to an ascendancy, only for the player's own characters
+    pub fn level_up(
+        &mut self,
+        name: &str,
+        class: &str,
+        level: u32,
+        zone: Option<&str>,
+        timestamp: &str,
+    ) -> Option<(Character, Option<Ascension>)> {
+        let own = self.is_own(name);
+        let existing = self.list(own).iter().position(|c| c.name == name);
+        let index = match existing {
+            Some(index) => {
+                let existing = &self.list(own)[index];
+                if timestamp <= existing.last_seen.as_str() {
+                    return None;
+                }
+                // Levels never go down, so a lower one means the name was reused
+                if level < existing.level {
+                    let character = self.new_character(name, class, level, timestamp);
+                    self.list_mut(own)[index] = character;
+                }
+                index
+            }
+            None => {
+                let character = self.new_character(name, class, level, timestamp);
+                self.list_mut(own).push(character);
+                self.list(own).len() - 1
+            }
+        };
+
+        let session_start = self.session_start.clone();
+        let character = &mut self.list_mut(own)[index];
+
+        // Classes only change once, from a base class to an ascendancy
+        let ascended = character.class != class
+            && character.ascension.is_none()
+            && is_base_class(&character.class)
+            && !is_base_class(class);
+        let ascension = if ascended {
+            let ascension = Ascension {
+                character: name.to_string(),
+                base_class: character.class.clone(),
+                ascendancy: class.to_string(),
+                level,
+                zone: zone.map(str::to_string),
+                timestamp: timestamp.to_string(),
+            };
+            character.base_class = Some(ascension.base_class.clone());
+            character.ascension = Some(ascension.clone());
+            Some(ascension)
+        } else {
+            None
+        };
+
+        character.class = class.to_string();
+        character.level = level;
+        character.last_seen = timestamp.to_string();
+        count_session(character, session_start);
+        let character = character.clone();
+
+        if let Some(ascension) = &ascension {
+            let mapping = AscendancyMapping {
+                base_class: ascension.base_class.clone(),
+                ascendancy: ascension.ascendancy.clone(),
+            };
+            if !self.ascendancies.contains(&mapping) {
+                self.ascendancies.push(mapping);
+            }
+        }
+        own.then_some((character, ascension))
+    }
+
+    fn list(&self, own: bool) -> &Vec<Character> {
+        if own {
+            &self.characters
+        } else {
+            &self.others
+        }
+    }
+
+    fn list_mut(&mut self, own: bool) -> &mut Vec<Character> {
+        if own {
+            &mut self.characters
+        } else {
+            &mut self.others
+        }
+    }
+
+    /// Marks a character seen levelling up as the player's own, or back as someone else's
+    pub fn set_own(&mut self, name: &str, own: bool) -> Result<Character, String> {
+        if let Some(character) = self.list(own).iter().find(|c| c.name == name) {
+            return Ok(character.clone());
+        }
+        let from = self.list_mut(!own);
+        let index = from
+            .iter()
+            .position(|c| c.name == name)
+            .ok_or_else(|| format!("No level-up seen for {}", name))?;
+        let character = from.remove(index);
+        self.list_mut(own).push(character.clone());
+        Ok(character)
+    }
+
+    fn new_character(&self, name: &str, class: &str, level: u32, timestamp: &str) -> Character {
+        let base_class = if is_base_class(class) {
+            Some(class.to_string())
+        } else {
+            self.ascendancies
+                .iter()
+                .find(|mapping| mapping.ascendancy == class)
+                .map(|mapping| mapping.base_class.clone())
+        };
+        Character {
+            name: name.to_string(),
+            class: class.to_string(),
+            base_class,
+            ascension: None,
+            level,
+            deaths: 0,
+            first_seen: timestamp.to_string(),
+            last_seen: timestamp.to_string(),
+            sessions: 0,
+            last_session_start: None,
+            last_death_at: None,
+        }
+    }
+
+    /// Deaths only count for the player's own characters, since party members die too
+    pub fn death(&mut self, name: &str, timestamp: &str) -> Option<Character> {
+        let session_start = self.session_start.clone();
+        let character = self.characters.iter_mut().find(|c| c.name == name)?;
+        if character
+            .last_death_at
+            .as_deref()
+            .is_some_and(|last| timestamp <= last)
+        {
+            return None;
+        }
+
+        character.deaths += 1;
+        character.last_death_at = Some(timestamp.to_string());
+        if timestamp > character.last_seen.as_str() {
+            character.last_seen = timestamp.to_string();
+        }
+        count_session(character, session_start);
+        Some(character.clone())
+    }
+
+    /// The player's own characters, most recently seen first
+    pub fn characters(&self) -> Vec<Character> {
+        sorted_by_last_seen(&self.characters)
+    }
+
+    /// Other characters seen levelling up this run, most recently seen first
+    pub fn others(&self) -> Vec<Character> {
+        sorted_by_last_seen(&self.others)
+    }
+}
+
+fn sorted_by_last_seen(characters: &[Character]) -> Vec<Character> {
+    let mut characters = characters.to_vec();
+    characters.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+    characters
+}
+
+fn is_base_class(class: &str) -> bool {
+    BASE_CLASSES.iter().any(|base| base.eq_ignore_ascii_case(class))
+}
+
+fn count_session(character: &mut Character, session_start: Option<String>) {
+    match session_start {
+        // None sorts before any session, so a first session always counts
+        Some(start) if character.last_session_start.as_deref() < Some(start.as_str()) => {
+            character.sessions += 1;
+            character.last_session_start = Some(start);
+        }
+        // Logs without a session marker still count as one session
+        None if character.sessions == 0 => character.sessions = 1,
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_ups_are_only_persisted_for_characters_marked_own() {
+        let mut roster = CharacterRoster::default();
+        assert!(roster.level_up("PartyMember", "Monk", 2, None, "2025/11/04 19:00:00").is_none());
+        assert!(roster.characters().is_empty());
+        assert_eq!(roster.others().len(), 1);
+
+        roster.set_own("PartyMember", true).unwrap();
+        let (character, _) = roster.level_up("PartyMember", "Monk", 3, None, "2025/11/04 19:10:00").unwrap();
+        assert_eq!(character.level, 3);
+        assert!(serde_json::to_string(&roster).unwrap().contains("PartyMember"));
+
+        assert!(roster.set_own("Stranger", true).is_err());
+    }
+
+    fn own_roster(name: &str, class: &str, level: u32, timestamp: &str) -> CharacterRoster {
+        let mut roster = CharacterRoster::default();
+        roster.level_up(name, class, level, None, timestamp);
+        roster.set_own(name, true).unwrap();
+        roster
+    }
+
+    #[test]
+    fn replaying_a_log_leaves_the_roster_unchanged() {
+        let mut roster = own_roster("Kekius", "Mercenary", 2, "2025/11/04 19:00:00");
+        roster.start_session("2025/11/04 18:59:00");
+        roster.level_up("Kekius", "Witchhunter", 33, None, "2025/11/04 20:00:00").unwrap();
+        roster.death("Kekius", "2025/11/04 20:05:00").unwrap();
+        let before = serde_json::to_string(&roster).unwrap();
+
+        // Same session read again, e.g. after restarting the watcher
+        roster.start_session("2025/11/04 18:59:00");
+        assert!(roster.level_up("Kekius", "Mercenary", 2, None, "2025/11/04 19:00:00").is_none());
+        assert!(roster.level_up("Kekius", "Witchhunter", 33, None, "2025/11/04 20:00:00").is_none());
+        assert!(roster.death("Kekius", "2025/11/04 20:05:00").is_none());
+        assert_eq!(serde_json::to_string(&roster).unwrap(), before);
+    }
+
+    #[test]
+    fn level_drop_starts_a_new_character() {
+        let mut roster = own_roster("Kekius", "Witch", 40, "2025/11/04 19:00:00");
+        roster.death("Kekius", "2025/11/04 19:30:00").unwrap();
+
+        let (character, ascension) = roster.level_up("Kekius", "Monk", 2, None, "2025/11/05 10:00:00").unwrap();
+        assert!(ascension.is_none());
+        assert_eq!((character.class.as_str(), character.level, character.deaths), ("Monk", 2, 0));
+        assert_eq!(character.first_seen, "2025/11/05 10:00:00");
+        assert_eq!(roster.characters().len(), 1);
+    }
+
+    #[test]
+    fn only_base_class_to_ascendancy_counts_as_ascension() {
+        let mut roster = own_roster("Kekius", "Mercenary", 30, "2025/11/04 19:00:00");
+
+        let (_, ascension) = roster.level_up("Kekius", "Witchhunter", 31, Some("Act 2"), "2025/11/04 19:10:00").unwrap();
+        let ascension = ascension.expect("ascension");
+        assert_eq!((ascension.base_class.as_str(), ascension.ascendancy.as_str()), ("Mercenary", "Witchhunter"));
+        assert_eq!(
+            roster.ascendancies,
+            [AscendancyMapping { base_class: "Mercenary".to_string(), ascendancy: "Witchhunter".to_string() }]
+        );
+
+        // A second ascendancy name, or a base class reported again, is not another ascension
+        let (_, ascension) = roster.level_up("Kekius", "Gemling Legionnaire", 32, None, "2025/11/04 19:20:00").unwrap();
+        assert!(ascension.is_none());
+        let mut other = own_roster("Amy", "Warrior", 10, "2025/11/04 19:00:00");
+        assert!(other.level_up("Amy", "Monk", 11, None, "2025/11/04 19:10:00").unwrap().1.is_none());
+    }
+}
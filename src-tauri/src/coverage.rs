@@ -0,0 +1,124 @@
+use crate::log_categorizer::strip_header;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Example lines kept per template
+const MAX_EXAMPLES: usize = 3;
+
+/// Fallback messages that share one normalized template
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateCoverage {
+    pub template: String,
+    pub count: usize,
+    pub examples: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CoverageReport {
+    pub total_entries: usize,
+    pub fallback_entries: usize,
+    pub distinct_templates: usize,
+    pub templates: Vec<TemplateCoverage>,
+}
+
+/// Collects messages that no category rule matched, grouped by template
+#[derive(Default)]
+pub struct CoverageTracker {
+    total_entries: usize,
+    fallback_entries: usize,
+    templates: HashMap<String, TemplateCoverage>,
+}
+
+impl CoverageTracker {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn record(&mut self, full_message: &str, fallback: bool) {
+        self.total_entries += 1;
+        if !fallback {
+            return;
+        }
+        self.fallback_entries += 1;
+
+        let first_line = full_message.lines().next().unwrap_or_default();
+        let body = strip_header(first_line);
+        let template = normalize_template(body);
+
+        let entry = self
+            .templates
+            .entry(template.clone())
+            .or_insert_with(|| TemplateCoverage {
+                template,
+                count: 0,
+                examples: Vec::new(),
+            });
+        entry.count += 1;
+        if entry.examples.len() < MAX_EXAMPLES && !entry.examples.iter().any(|e| e == body) {
+            entry.examples.push(body.to_string());
+        }
+    }
+
+    /// Templates ordered by how often they fell through, most frequent first
+    pub fn report(&self, limit: Option<usize>) -> CoverageReport {
+        let mut templates: Vec<TemplateCoverage> = self.templates.values().cloned().collect();
+        templates.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.template.cmp(&b.template)));
+        if let Some(limit) = limit {
+            templates.truncate(limit);
+        }
+
+        CoverageReport {
+            total_entries: self.total_entries,
+            fallback_entries: self.fallback_entries,
+            distinct_templates: self.templates.len(),
+            templates,
+        }
+    }
+}
+
+/// Masks the variable parts of a message so similar lines group together
+/// Paths become `<path>`, hex ids `<id>` and every digit run `<n>`
+pub fn normalize_template(body: &str) -> String {
+    body.split(' ')
+        .map(normalize_token)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn normalize_token(token: &str) -> String {
+    let trimmed = token.trim_matches(|c: char| "[](){}\"',;:".contains(c));
+
+    if trimmed.len() > 1 && (trimmed.contains('/') || trimmed.contains('\\')) {
+        return token.replacen(trimmed, "<path>", 1);
+    }
+
+    if is_hex_id(trimmed) {
+        return token.replacen(trimmed, "<id>", 1);
+    }
+
+    let mut normalized = String::with_capacity(token.len());
+    let mut in_digits = false;
+    for c in token.chars() {
+        if c.is_ascii_digit() {
+            if !in_digits {
+                normalized.push_str("<n>");
+                in_digits = true;
+            }
+        } else {
+            normalized.push(c);
+            in_digits = false;
+        }
+    }
+    normalized
+}
+
+fn is_hex_id(token: &str) -> bool {
+    if let Some(hex) = token.strip_prefix("0x") {
+        return !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+
+    token.len() >= 8
+        && token.chars().all(|c| c.is_ascii_hexdigit())
+        && token.chars().any(|c| c.is_ascii_digit())
+        && token.chars().any(|c| c.is_ascii_alphabetic())
+}
@@ -0,0 +1,145 @@
+use crate::log_categorizer::strip_header;
+use crate::network_stats::{LatencySample, INSTANCE_SERVER};
+use crate::zone_tracker::ZoneChange;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Connection to the instance server hosting one area
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceConnection {
+    pub address: String,
+    pub connected_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_ms: Option<u32>,
+    // Area loaded after connecting, filled in by the next zone change
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_level: Option<u32>,
+}
+
+/// Connections to one instance server across the log
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceServerSummary {
+    pub address: String,
+    pub connections: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_connect_ms: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connect_ms: Option<u32>,
+    pub areas: Vec<String>,
+}
+
+/// Pattern: "Async connecting to 198.51.100.7:6112" -> "198.51.100.7:6112"
+pub fn parse_instance_address(full_message: &str) -> Option<&str> {
+    let body = strip_header(full_message.lines().next()?);
+    let address = body.strip_prefix("Async connecting to ")?.trim();
+    if address.contains(':') && !address.contains(' ') {
+        Some(address)
+    } else {
+        None
+    }
+}
+
+/// Follows "Got Instance Details" -> "Async connecting to" -> connect time -> zone change
+#[derive(Default)]
+pub struct InstanceTracker {
+    // Set by "Got Instance Details" so unrelated connects aren't taken for instances
+    details_received: bool,
+    // The latest connection is still waiting for its connect time / area
+    awaiting_connect_time: bool,
+    awaiting_area: bool,
+    connections: Vec<InstanceConnection>,
+}
+
+impl InstanceTracker {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Feeds one log entry; returns the instance address when it starts a new connection
+    pub fn observe(
+        &mut self,
+        full_message: &str,
+        timestamp: &str,
+        latency: Option<&LatencySample>,
+        zone_change: Option<&ZoneChange>,
+    ) -> Option<String> {
+        if strip_header(full_message).starts_with("Got Instance Details") {
+            self.details_received = true;
+            return None;
+        }
+
+        if self.details_received {
+            if let Some(address) = parse_instance_address(full_message) {
+                self.details_received = false;
+                self.awaiting_connect_time = true;
+                self.awaiting_area = true;
+                self.connections.push(InstanceConnection {
+                    address: address.to_string(),
+                    connected_at: timestamp.to_string(),
+                    connect_ms: None,
+                    area_name: None,
+                    area_level: None,
+                });
+                return Some(address.to_string());
+            }
+        }
+
+        let current = self.connections.last_mut()?;
+        if let Some(sample) = latency.filter(|sample| sample.host == INSTANCE_SERVER) {
+            if self.awaiting_connect_time {
+                current.connect_ms = Some(sample.latency_ms);
+                self.awaiting_connect_time = false;
+            }
+        }
+        if let Some(zone) = zone_change {
+            if self.awaiting_area {
+                current.area_name = Some(zone.area_name.clone());
+                current.area_level = zone.area_level;
+                self.awaiting_area = false;
+            }
+        }
+        None
+    }
+
+    /// Connections, newest first
+    pub fn connections(&self) -> Vec<InstanceConnection> {
+        self.connections.iter().rev().cloned().collect()
+    }
+
+    /// Per-server connect times and the areas each one hosted, slowest first
+    pub fn servers(&self) -> Vec<InstanceServerSummary> {
+        let mut by_address: HashMap<&str, Vec<&InstanceConnection>> = HashMap::new();
+        for connection in &self.connections {
+            by_address.entry(connection.address.as_str()).or_default().push(connection);
+        }
+
+        let mut servers: Vec<InstanceServerSummary> = by_address
+            .into_iter()
+            .map(|(address, connections)| {
+                let times: Vec<u32> = connections.iter().filter_map(|c| c.connect_ms).collect();
+                let mut areas: Vec<String> = Vec::new();
+                for area in connections.iter().filter_map(|c| c.area_name.as_ref()) {
+                    if !areas.contains(area) {
+                        areas.push(area.clone());
+                    }
+                }
+                InstanceServerSummary {
+                    address: address.to_string(),
+                    connections: connections.len(),
+                    average_connect_ms: (!times.is_empty())
+                        .then(|| times.iter().sum::<u32>() / times.len() as u32),
+                    max_connect_ms: times.iter().max().copied(),
+                    areas,
+                }
+            })
+            .collect();
+        servers.sort_by(|a, b| {
+            b.average_connect_ms
+                .cmp(&a.average_connect_ms)
+                .then_with(|| a.address.cmp(&b.address))
+        });
+        servers
+    }
+}
@@ -0,0 +1,172 @@
+use crate::log_categorizer::strip_header;
+use serde::Serialize;
+
+const SUBSYSTEM_PREFIX: &str = "[Item Filter] ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterAction {
+    DownloadStarted,
+    HashReported,
+}
+
+/// Structured form of an `[Item Filter]` line
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemFilterEvent {
+    pub action: FilterAction,
+    pub filter_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    pub timestamp: String,
+}
+
+/// Parses an `[Item Filter]` line
+/// Patterns:
+///   "Preparing to download online filter XVEokZIq"
+///   "Hash for online filter XVEokZIq is: bcfe77484ea1b4865aa2106ec733a86d"
+/// These are the only formats confirmed from a real log, so other filter lines
+/// return None until their wording is known.
+pub fn parse_item_filter_event(full_message: &str, timestamp: &str) -> Option<ItemFilterEvent> {
+    let body = strip_header(full_message.lines().next()?).strip_prefix(SUBSYSTEM_PREFIX)?;
+
+    let (action, filter_id, hash) = if let Some(id) = body.strip_prefix("Preparing to download online filter ") {
+        (FilterAction::DownloadStarted, id, None)
+    } else {
+        let (id, hash) = body
+            .strip_prefix("Hash for online filter ")?
+            .split_once(" is: ")?;
+        let hash = hash.trim();
+        if hash.is_empty() {
+            return None;
+        }
+        (FilterAction::HashReported, id, Some(hash.to_string()))
+    };
+
+    let filter_id = filter_id.trim();
+    if filter_id.is_empty() || filter_id.contains(' ') {
+        return None;
+    }
+    Some(ItemFilterEvent {
+        action,
+        filter_id: filter_id.to_string(),
+        hash,
+        timestamp: timestamp.to_string(),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadOutcome {
+    // Still waiting for the hash line
+    Pending,
+    // The client reported the hash of the downloaded version
+    Completed,
+    // Another download or a client restart came before any hash
+    Incomplete,
+}
+
+/// One online filter download, from the "Preparing to download" line to its hash
+#[derive(Debug, Clone, Serialize)]
+pub struct FilterHistoryEntry {
+    pub timestamp: String,
+    pub filter_id: String,
+    pub outcome: DownloadOutcome,
+    // None until the client reports the hash of the downloaded version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    // Filter and version of the last download that reported a hash
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_filter_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_hash: Option<String>,
+}
+
+/// Every online filter download with the version it reported
+/// The client has no confirmed failure line, so a download counts as incomplete
+/// when the next download or client session starts before its hash is reported.
+#[derive(Default)]
+pub struct ItemFilterHistory {
+    entries: Vec<FilterHistoryEntry>,
+}
+
+impl ItemFilterHistory {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn start_session(&mut self) {
+        self.close_pending();
+    }
+
+    fn close_pending(&mut self) {
+        for entry in &mut self.entries {
+            if entry.outcome == DownloadOutcome::Pending {
+                entry.outcome = DownloadOutcome::Incomplete;
+            }
+        }
+    }
+
+    pub fn record(&mut self, event: &ItemFilterEvent) {
+        match event.action {
+            FilterAction::DownloadStarted => {
+                self.close_pending();
+                let previous = self.entries.iter().rev().find(|entry| entry.hash.is_some());
+                let entry = FilterHistoryEntry {
+                    timestamp: event.timestamp.clone(),
+                    filter_id: event.filter_id.clone(),
+                    outcome: DownloadOutcome::Pending,
+                    hash: None,
+                    previous_filter_id: previous.map(|entry| entry.filter_id.clone()),
+                    previous_hash: previous.and_then(|entry| entry.hash.clone()),
+                };
+                self.entries.push(entry);
+            }
+            FilterAction::HashReported => {
+                let pending = self.entries.iter_mut().rev().find(|entry| {
+                    entry.filter_id == event.filter_id && entry.outcome == DownloadOutcome::Pending
+                });
+                if let Some(entry) = pending {
+                    entry.hash = event.hash.clone();
+                    entry.outcome = DownloadOutcome::Completed;
+                }
+            }
+        }
+    }
+
+    /// Downloads, newest first
+    pub fn entries(&self) -> Vec<FilterHistoryEntry> {
+        self.entries.iter().rev().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "2025/11/04 19:20:50 187967859 3ef232c2 [INFO Client 7776] ";
+
+    fn record(history: &mut ItemFilterHistory, body: &str, timestamp: &str) {
+        let line = format!("{}[Item Filter] {}", HEADER, body);
+        history.record(&parse_item_filter_event(&line, timestamp).expect("item filter line"));
+    }
+
+    #[test]
+    fn download_without_hash_is_incomplete_once_superseded() {
+        let mut history = ItemFilterHistory::default();
+        record(&mut history, "Preparing to download online filter XVEokZIq", "2025/11/04 19:00:00");
+        assert_eq!(history.entries()[0].outcome, DownloadOutcome::Pending);
+
+        record(&mut history, "Preparing to download online filter XVEokZIq", "2025/11/04 19:05:00");
+        record(&mut history, "Hash for online filter XVEokZIq is: bcfe77484ea1b4865aa2106ec733a86d", "2025/11/04 19:05:01");
+        let outcomes: Vec<_> = history.entries().iter().map(|entry| entry.outcome).collect();
+        assert_eq!(outcomes, [DownloadOutcome::Completed, DownloadOutcome::Incomplete]);
+    }
+
+    #[test]
+    fn session_start_closes_a_pending_download() {
+        let mut history = ItemFilterHistory::default();
+        record(&mut history, "Preparing to download online filter XVEokZIq", "2025/11/04 19:00:00");
+        history.start_session();
+        assert_eq!(history.entries()[0].outcome, DownloadOutcome::Incomplete);
+    }
+}
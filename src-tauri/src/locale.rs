@@ -0,0 +1,110 @@
+/// Client-language specific text for the gameplay messages we detect
+/// Only English ships: add a language once its strings are copied from a real
+/// client log, since a guessed translation turns ordinary dialogue into events.
+/// Templates use `{name}`, `{class}`, `{level}`, `{id}`, `{reward}`, `{mode}`, `{reply}`,
+/// `{duration}`, `{count}` and `{source}` placeholders; everything else is matched literally against the text
+/// after the ": " system prefix, so fix them here if a patch changes the wording.
+#[derive(Debug)]
+pub struct LocalePack {
+    pub death: &'static str,
+    pub level_up: &'static str,
+    pub passive_allocated: &'static str,
+    pub passive_unallocated: &'static str,
+    pub passives_reset: &'static str,
+    pub item_received: &'static str,
+    pub trade_accepted: &'static str,
+    pub trade_cancelled: &'static str,
+    pub area_joined: &'static str,
+    pub area_left: &'static str,
+    pub status_on: &'static str,
+    pub status_off: &'static str,
+    // Slash command output of /played, /age, /deaths and /passives
+    pub played: &'static str,
+    pub character_age: &'static str,
+    pub never_died: &'static str,
+    pub died: &'static str,
+    pub passive_points: &'static str,
+}
+
+pub static ENGLISH: LocalePack = LocalePack {
+    death: "{name} has been slain",
+    level_up: "{name} ({class}) is now level {level}",
+    passive_allocated: "Successfully allocated passive skill id: {id}",
+    passive_unallocated: "Successfully unallocated passive skill id: {id}",
+    passives_reset: "All passive skills have been reset",
+    item_received: "You have received {reward}",
+    trade_accepted: "Trade accepted",
+    trade_cancelled: "Trade cancelled",
+    area_joined: "{name} has joined the area",
+    area_left: "{name} has left the area",
+    status_on: "{mode} mode is now ON. Autoreply \"{reply}\"",
+    status_off: "{mode} mode is now OFF",
+    played: "You have played for {duration}",
+    character_age: "Your character was created {duration} ago",
+    never_died: "You have never died",
+    died: "You have died {count} time",
+    passive_points: "You have {count} Passive Skill Point{source}",
+};
+
+/// Longest literal run of a template
+/// The categorizer uses it to pre-filter lines before matching the whole template
+pub fn template_marker(template: &'static str) -> &'static str {
+    let mut longest = "";
+    let mut rest = template;
+    loop {
+        let literal_end = rest.find('{').unwrap_or(rest.len());
+        let literal = rest[..literal_end].trim();
+        if literal.chars().count() > longest.chars().count() {
+            longest = literal;
+        }
+        match rest[literal_end..].find('}') {
+            Some(close) => rest = &rest[literal_end + close + 1..],
+            None => break,
+        }
+    }
+    longest
+}
+
+/// Matches the start of `text` against a template and returns the placeholder values
+/// Text after the final literal (e.g. a trailing ".") is ignored. Works on any
+/// Unicode text since literals are located by substring search, not byte offsets.
+pub fn match_template<'t>(template: &'static str, text: &'t str) -> Option<Vec<(&'static str, &'t str)>> {
+    let mut values = Vec::new();
+    let mut template_rest = template;
+    let mut text_rest = text;
+
+    // Leading literal must match exactly
+    let first_open = template_rest.find('{').unwrap_or(template_rest.len());
+    text_rest = text_rest.strip_prefix(&template_rest[..first_open])?;
+    template_rest = &template_rest[first_open..];
+
+    while let Some(stripped) = template_rest.strip_prefix('{') {
+        let close = stripped.find('}')?;
+        let placeholder = &stripped[..close];
+        template_rest = &stripped[close + 1..];
+
+        let literal_end = template_rest.find('{').unwrap_or(template_rest.len());
+        let literal = &template_rest[..literal_end];
+        template_rest = &template_rest[literal_end..];
+
+        if literal.is_empty() {
+            // Trailing placeholder takes the rest of the text
+            values.push((placeholder, text_rest.trim_end()));
+            text_rest = "";
+        } else {
+            let value_end = text_rest.find(literal)?;
+            values.push((placeholder, &text_rest[..value_end]));
+            text_rest = &text_rest[value_end + literal.len()..];
+        }
+    }
+
+    Some(values)
+}
+
+/// Looks up a placeholder value returned by `match_template`
+pub fn field<'t>(values: &[(&'static str, &'t str)], placeholder: &str) -> Option<&'t str> {
+    values
+        .iter()
+        .find(|(name, _)| *name == placeholder)
+        .map(|(_, value)| *value)
+}
@@ -1,16 +1,124 @@
+use crate::locale::{self, template_marker, LocalePack};
+use aho_corasick::AhoCorasick;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Identifier of a log category
+/// Serialized as its display name, so the frontend still sees "Level Up" etc.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Category {
+    Warnings,
+    Trade,
+    Death,
+    LevelUp,
+    Skill,
+    Gameplay,
+    Status,
+    Guild,
+    ItemFilter,
+    Graphics,
+    Engine,
+    Audio,
+    Network,
+    Dialogue,
+}
+
+impl Category {
+    /// Category used when no rule matches
+    pub const FALLBACK: Category = Category::Engine;
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Warnings => "Warnings",
+            Category::Trade => "Trade",
+            Category::Death => "Death",
+            Category::LevelUp => "Level Up",
+            Category::Skill => "Skill",
+            Category::Gameplay => "Gameplay",
+            Category::Status => "Status",
+            Category::Guild => "Guild",
+            Category::ItemFilter => "Item Filter",
+            Category::Graphics => "Graphics",
+            Category::Engine => "Engine",
+            Category::Audio => "Audio",
+            Category::Network => "Network",
+            Category::Dialogue => "Dialogue",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<Category> for String {
+    fn from(category: Category) -> Self {
+        category.as_str().to_string()
+    }
+}
+
+impl TryFrom<String> for Category {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        Ok(match name.as_str() {
+            "Warnings" => Category::Warnings,
+            "Trade" => Category::Trade,
+            "Death" => Category::Death,
+            "Level Up" => Category::LevelUp,
+            "Skill" => Category::Skill,
+            "Gameplay" => Category::Gameplay,
+            "Status" => Category::Status,
+            "Guild" => Category::Guild,
+            "Item Filter" => Category::ItemFilter,
+            "Graphics" => Category::Graphics,
+            "Engine" => Category::Engine,
+            "Audio" => Category::Audio,
+            "Network" => Category::Network,
+            "Dialogue" => Category::Dialogue,
+            _ => return Err(format!("Unknown category: {}", name)),
+        })
+    }
+}
+
+/// Which filter preset a category belongs to in the UI
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CategoryGroup {
+    Gameplay,
+    System,
+}
+
 #[derive(Debug, Clone)]
 pub struct LogCategory {
-    pub name: String,
+    pub name: Category,
     pub priority: u8, // Lower number = higher priority
+    pub description: String,
+    pub color: String, // Default display colour as a hex string
+    pub group: CategoryGroup,
     pub patterns: CategoryPatterns,
 }
 
+/// Category metadata returned to the frontend by `list_categories`
+#[derive(Debug, Clone, Serialize)]
+pub struct CategoryInfo {
+    pub name: Category,
+    pub priority: u8,
+    pub description: String,
+    pub color: String,
+    pub group: CategoryGroup,
+}
+
 #[derive(Debug, Clone)]
 pub struct CategoryPatterns {
     pub required_contains: Vec<String>,
     pub any_contains: Vec<String>,
     pub excluded_contains: Vec<String>,
-    pub custom_validator: Option<fn(&str) -> bool>,
+    pub custom_validator: Option<fn(&str, &LocalePack) -> bool>,
 }
 
 impl CategoryPatterns {
@@ -38,12 +146,30 @@ impl CategoryPatterns {
         self
     }
 
-    pub fn custom(mut self, validator: fn(&str) -> bool) -> Self {
+    /// Adds the bracketed tag of every subsystem mapped to `category` to the any-of list
+    pub fn any_of_subsystems(mut self, category: Category) -> Self {
+        self.any_contains.extend(
+            SUBSYSTEM_TAGS
+                .iter()
+                .filter(|(_, tag_category)| *tag_category == category)
+                .map(|(tag, _)| format!("[{}]", tag)),
+        );
+        self
+    }
+
+    /// Excludes messages carrying the bracketed tag of any known subsystem
+    pub fn exclude_subsystems(mut self) -> Self {
+        self.excluded_contains
+            .extend(SUBSYSTEM_TAGS.iter().map(|(tag, _)| format!("[{}]", tag)));
+        self
+    }
+
+    pub fn custom(mut self, validator: fn(&str, &LocalePack) -> bool) -> Self {
         self.custom_validator = Some(validator);
         self
     }
 
-    pub fn matches(&self, message: &str) -> bool {
+    pub fn matches(&self, message: &str, locale: &LocalePack) -> bool {
         for exclude in &self.excluded_contains {
             if message.contains(exclude) {
                 return false;
@@ -64,83 +190,336 @@ impl CategoryPatterns {
         }
 
         if let Some(validator) = self.custom_validator {
-            if !validator(message) {
+            if !validator(message, locale) {
                 return false;
             }
         }
 
         true
     }
+
+    /// Evaluates every clause against the message without short-circuiting,
+    /// so the caller can see exactly which ones passed or failed
+    pub fn explain(&self, message: &str, locale: &LocalePack) -> PatternExplanation {
+        let required = if self.required_contains.is_empty() {
+            None
+        } else {
+            let missing: Vec<String> = self
+                .required_contains
+                .iter()
+                .filter(|pattern| !message.contains(pattern.as_str()))
+                .cloned()
+                .collect();
+            Some(ClauseCheck { passed: missing.is_empty(), patterns: missing })
+        };
+
+        let any_of = if self.any_contains.is_empty() {
+            None
+        } else {
+            let found: Vec<String> = self
+                .any_contains
+                .iter()
+                .filter(|pattern| message.contains(pattern.as_str()))
+                .cloned()
+                .collect();
+            Some(ClauseCheck { passed: !found.is_empty(), patterns: found })
+        };
+
+        let exclude = if self.excluded_contains.is_empty() {
+            None
+        } else {
+            let hits: Vec<String> = self
+                .excluded_contains
+                .iter()
+                .filter(|pattern| message.contains(pattern.as_str()))
+                .cloned()
+                .collect();
+            Some(ClauseCheck { passed: hits.is_empty(), patterns: hits })
+        };
+
+        let custom_validator = self.custom_validator.map(|validator| validator(message, locale));
+
+        PatternExplanation {
+            matched: self.matches(message, locale),
+            required,
+            any_of,
+            exclude,
+            custom_validator,
+        }
+    }
+}
+
+/// Outcome of a single pattern clause
+/// `patterns` lists the missing required patterns, the any-of patterns that
+/// were found, or the excluded patterns that were hit
+#[derive(Debug, Clone, Serialize)]
+pub struct ClauseCheck {
+    pub passed: bool,
+    pub patterns: Vec<String>,
+}
+
+/// Per-clause breakdown of a `CategoryPatterns` evaluation
+/// Clauses that are not configured for the category are `None`
+#[derive(Debug, Clone, Serialize)]
+pub struct PatternExplanation {
+    pub matched: bool,
+    pub required: Option<ClauseCheck>,
+    pub any_of: Option<ClauseCheck>,
+    pub exclude: Option<ClauseCheck>,
+    pub custom_validator: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CategoryExplanation {
+    pub name: Category,
+    pub priority: u8,
+    #[serde(flatten)]
+    pub result: PatternExplanation,
+}
+
+/// Full trace of how a message was categorized
+/// `tried` holds every category in the order `categorize` checks them
+#[derive(Debug, Clone, Serialize)]
+pub struct CategorizationExplanation {
+    pub category: Category,
+    pub fallback: bool,
+    pub tried: Vec<CategoryExplanation>,
+}
+
+/// A category rule with its patterns resolved to ids in the shared automaton
+struct CompiledRule {
+    name: Category,
+    required: Vec<usize>,
+    any_of: Vec<usize>,
+    excluded: Vec<usize>,
+    custom_validator: Option<fn(&str, &LocalePack) -> bool>,
+}
+
+impl CompiledRule {
+    fn matches(&self, found: &PatternSet, message: &str, locale: &LocalePack) -> bool {
+        if self.excluded.iter().any(|&id| found.contains(id)) {
+            return false;
+        }
+
+        if !self.required.iter().all(|&id| found.contains(id)) {
+            return false;
+        }
+
+        if !self.any_of.is_empty() && !self.any_of.iter().any(|&id| found.contains(id)) {
+            return false;
+        }
+
+        match self.custom_validator {
+            Some(validator) => validator(message, locale),
+            None => true,
+        }
+    }
+}
+
+/// Bitset of the pattern ids present in a message
+struct PatternSet {
+    words: Vec<u64>,
+}
+
+impl PatternSet {
+    fn new(pattern_count: usize) -> Self {
+        Self {
+            words: vec![0; pattern_count / 64 + 1],
+        }
+    }
+
+    fn insert(&mut self, id: usize) {
+        self.words[id / 64] |= 1 << (id % 64);
+    }
+
+    fn contains(&self, id: usize) -> bool {
+        self.words[id / 64] & (1 << (id % 64)) != 0
+    }
 }
 
 pub struct LogCategorizer {
+    locale: &'static LocalePack,
     categories: Vec<LogCategory>,
+    /// Every distinct pattern of every category, matched in a single pass
+    automaton: AhoCorasick,
+    pattern_count: usize,
+    /// Rules pre-sorted by priority, in the order `categorize` checks them
+    rules: Vec<CompiledRule>,
 }
 
 impl LogCategorizer {
     pub fn new() -> Self {
+        Self::with_locale(&locale::ENGLISH)
+    }
+
+    /// Builds a categorizer that recognises gameplay lines in the given client language
+    pub fn with_locale(locale: &'static LocalePack) -> Self {
+        let mut categories = Self::define_categories(locale);
+        // Stable sort keeps definition order between categories of equal priority
+        categories.sort_by_key(|cat| cat.priority);
+
+        let mut patterns: Vec<String> = Vec::new();
+        let mut pattern_ids: HashMap<String, usize> = HashMap::new();
+        let mut intern = |list: &[String]| -> Vec<usize> {
+            list.iter()
+                .map(|pattern| {
+                    *pattern_ids.entry(pattern.clone()).or_insert_with(|| {
+                        patterns.push(pattern.clone());
+                        patterns.len() - 1
+                    })
+                })
+                .collect()
+        };
+
+        let rules: Vec<CompiledRule> = categories
+            .iter()
+            .map(|category| CompiledRule {
+                name: category.name.clone(),
+                required: intern(&category.patterns.required_contains),
+                any_of: intern(&category.patterns.any_contains),
+                excluded: intern(&category.patterns.excluded_contains),
+                custom_validator: category.patterns.custom_validator,
+            })
+            .collect();
+
+        let automaton = AhoCorasick::new(&patterns)
+            .expect("category patterns should always compile");
+
         Self {
-            categories: Self::define_categories(),
+            locale,
+            categories,
+            automaton,
+            pattern_count: patterns.len(),
+            rules,
         }
     }
 
-    pub fn categorize(&self, full_message: &str, _first_line: &str) -> String {
-        let mut sorted_categories = self.categories.clone();
-        sorted_categories.sort_by_key(|cat| cat.priority);
+    pub fn locale(&self) -> &'static LocalePack {
+        self.locale
+    }
+
+    /// Returns the first matching category, or None when the message falls
+    /// through to `Category::FALLBACK`
+    pub fn categorize(&self, full_message: &str, _first_line: &str) -> Option<Category> {
+        let mut found = PatternSet::new(self.pattern_count);
+        for mat in self.automaton.find_overlapping_iter(full_message) {
+            found.insert(mat.pattern().as_usize());
+        }
 
-        for category in sorted_categories {
-            if category.patterns.matches(full_message) {
-                return category.name;
+        for rule in &self.rules {
+            if rule.matches(&found, full_message, self.locale) {
+                return Some(rule.name.clone());
             }
         }
 
-        "Engine".to_string()
+        None
+    }
+
+    /// Metadata for every category, in priority order
+    pub fn list_categories(&self) -> Vec<CategoryInfo> {
+        self.categories
+            .iter()
+            .map(|category| CategoryInfo {
+                name: category.name.clone(),
+                priority: category.priority,
+                description: category.description.clone(),
+                color: category.color.clone(),
+                group: category.group,
+            })
+            .collect()
     }
 
-    fn define_categories() -> Vec<LogCategory> {
+    /// Explains the decision `categorize` makes for the same message
+    pub fn explain(&self, full_message: &str) -> CategorizationExplanation {
+        let tried: Vec<CategoryExplanation> = self
+            .categories
+            .iter()
+            .map(|category| CategoryExplanation {
+                result: category.patterns.explain(full_message, self.locale),
+                name: category.name.clone(),
+                priority: category.priority,
+            })
+            .collect();
+
+        let winner = tried.iter().find(|cat| cat.result.matched);
+
+        CategorizationExplanation {
+            category: winner.map_or(Category::FALLBACK, |cat| cat.name.clone()),
+            fallback: winner.is_none(),
+            tried,
+        }
+    }
+
+    fn define_categories(locale: &'static LocalePack) -> Vec<LogCategory> {
         vec![
             // Priority 1: Warnings (highest priority - catch all warning levels)
             LogCategory {
-                name: "Warnings".to_string(),
+                name: Category::Warnings,
                 priority: 1,
+                description: "Client warnings, critical and error lines".to_string(),
+                color: "#f87171".to_string(),
+                group: CategoryGroup::System,
                 patterns: CategoryPatterns::new()
                     .any_of(vec!["[WARN", "[CRIT", "[ERROR"]),
             },
 
             // Priority 2: Trade and chat messages
             LogCategory {
-                name: "Trade".to_string(),
+                name: Category::Trade,
                 priority: 2,
+                description: "Chat channels, whispers and trade outcomes".to_string(),
+                color: "#ffd255".to_string(),
+                group: CategoryGroup::Gameplay,
                 patterns: CategoryPatterns::new()
                     .custom(is_trade_or_chat_message),
             },
 
             // Priority 3: Player actions
             LogCategory {
-                name: "Death".to_string(),
+                name: Category::Death,
                 priority: 3,
+                description: "Character deaths".to_string(),
+                color: "#f87171".to_string(),
+                group: CategoryGroup::Gameplay,
                 patterns: CategoryPatterns::new()
-                    .required(vec!["has been slain"]),
+                    .required(vec![template_marker(locale.death)])
+                    .custom(is_death_message),
             },
 
             LogCategory {
-                name: "Level Up".to_string(),
+                name: Category::LevelUp,
                 priority: 3,
+                description: "Character level-ups".to_string(),
+                color: "#ffd255".to_string(),
+                group: CategoryGroup::Gameplay,
                 patterns: CategoryPatterns::new()
-                    .required(vec!["is now level"]),
+                    .required(vec![template_marker(locale.level_up)])
+                    .custom(is_level_up_message),
             },
 
             LogCategory {
-                name: "Skill".to_string(),
+                name: Category::Skill,
                 priority: 3,
+                description: "Skill points, rewards and passive allocations".to_string(),
+                color: "#c084fc".to_string(),
+                group: CategoryGroup::Gameplay,
                 patterns: CategoryPatterns::new()
-                    .any_of(vec!["have received", "Successfully allocated passive skill"]),
+                    .any_of(vec![
+                        template_marker(locale.item_received),
+                        template_marker(locale.passive_allocated),
+                        template_marker(locale.passive_unallocated),
+                        locale.passives_reset,
+                    ])
+                    .custom(is_skill_message),
             },
 
             // Priority 4: Game mechanics and interactions
             LogCategory {
-                name: "Gameplay".to_string(),
+                name: Category::Gameplay,
                 priority: 4,
+                description: "Item usage and in-game action feedback".to_string(),
+                color: "#fb923c".to_string(),
+                group: CategoryGroup::Gameplay,
                 patterns: CategoryPatterns::new()
                     .any_of(vec![
                         "Failed to apply item:",
@@ -151,143 +530,325 @@ impl LogCategorizer {
                     ]),
             },
 
+            LogCategory {
+                name: Category::Status,
+                priority: 4,
+                description: "Players joining or leaving, AFK/DND changes and slash command output".to_string(),
+                color: "#2dd4bf".to_string(),
+                group: CategoryGroup::Gameplay,
+                patterns: CategoryPatterns::new()
+                    .any_of(vec![
+                        template_marker(locale.area_joined),
+                        template_marker(locale.area_left),
+                        template_marker(locale.status_on),
+                        template_marker(locale.status_off),
+                        template_marker(locale.played),
+                        template_marker(locale.character_age),
+                        template_marker(locale.never_died),
+                        template_marker(locale.died),
+                        template_marker(locale.passive_points),
+                    ])
+                    .custom(is_status_message),
+            },
+
             // Priority 5: Guild activities
             LogCategory {
-                name: "Guild".to_string(),
+                name: Category::Guild,
                 priority: 5,
+                description: "Guild membership and announcements".to_string(),
+                color: "#34d399".to_string(),
+                group: CategoryGroup::Gameplay,
                 patterns: CategoryPatterns::new()
                     .any_of(vec!["Joined guild", "guild named", "&: GUILD UPDATE:", "GUILD UPDATE"]),
             },
 
             // Priority 6: System categories
             LogCategory {
-                name: "Item Filter".to_string(),
+                name: Category::ItemFilter,
                 priority: 6,
+                description: "Item filter downloads and loading".to_string(),
+                color: "#f472b6".to_string(),
+                group: CategoryGroup::System,
                 patterns: CategoryPatterns::new()
-                    .required(vec!["[Item Filter]"]),
+                    .any_of_subsystems(Category::ItemFilter),
             },
 
             LogCategory {
-                name: "Graphics".to_string(),
+                name: Category::Graphics,
                 priority: 6,
+                description: "Rendering, shaders, scenes and assets".to_string(),
+                color: "#60a5fa".to_string(),
+                group: CategoryGroup::System,
                 patterns: CategoryPatterns::new()
                     .any_of(vec![
-                        "[SHADER]", "[TEXTURE]", "[RENDER]", "[VULKAN]", "[SCENE]",
                         "Shader uses incorrect vertex layout", "Signature:",
-                        "Metadata/", ".fxgraph", "EngineGraphs", "[MESH]", "[MAT]",
-                        "[TRAILS]", "[GRAPH]", "[VIDEO]", "[PARTICLE]", "[STREAMLINE]"
-                    ]),
+                        "Metadata/", ".fxgraph", "EngineGraphs"
+                    ])
+                    .any_of_subsystems(Category::Graphics),
             },
 
             LogCategory {
-                name: "Engine".to_string(),
+                name: Category::Engine,
                 priority: 6,
+                description: "Engine internals and anything left uncategorized".to_string(),
+                color: "#666666".to_string(),
+                group: CategoryGroup::System,
                 patterns: CategoryPatterns::new()
-                    .any_of(vec![
-                        "[ENTITY]", "[ENGINE]", "[JOB]", "[STORAGE]", "[BUNDLE]",
-                        "[WINDOW]", "Client-Safe Instance ID", "Generating level",
-                        "[RESOURCE]"
-                    ]),
+                    .any_of(vec!["Client-Safe Instance ID", "Generating level"])
+                    .any_of_subsystems(Category::Engine),
             },
 
             LogCategory {
-                name: "Audio".to_string(),
+                name: Category::Audio,
                 priority: 6,
+                description: "Sound and audio banks".to_string(),
+                color: "#818cf8".to_string(),
+                group: CategoryGroup::System,
                 patterns: CategoryPatterns::new()
-                    .any_of(vec!["[SOUND]", "[AUDIO]"]),
+                    .any_of_subsystems(Category::Audio),
             },
 
             LogCategory {
-                name: "Network".to_string(),
+                name: Category::Network,
                 priority: 6,
+                description: "Patching, login and instance connections".to_string(),
+                color: "#888888".to_string(),
+                group: CategoryGroup::System,
                 patterns: CategoryPatterns::new()
                     .any_of(vec![
-                        "[HTTP2]", "User agent:", "Using backend:", "Send patching protocol",
+                        "User agent:", "Using backend:", "Send patching protocol",
                         "Web root:", "Backup Web root:", "Requesting root contents",
                         "Queue file to download", "Got file list", "Requesting folder",
                         ".datc64.bundle.bin", "Connecting to", "Connected to",
                         "Got Instance Details", "Connect time to instance",
                         "patch-poe", "poecdn.com", "Async connecting to",
                         "pathofexile2.com", "login.pathofexile2.com"
-                    ]),
+                    ])
+                    .any_of_subsystems(Category::Network),
             },
 
             // Priority 7: Dialogue (after system exclusions)
             LogCategory {
-                name: "Dialogue".to_string(),
+                name: Category::Dialogue,
                 priority: 7,
+                description: "NPC and boss dialogue".to_string(),
+                color: "#fbbf24".to_string(),
+                group: CategoryGroup::Gameplay,
                 patterns: CategoryPatterns::new()
                     .required(vec![": "])
                     .exclude(vec![
-                        "[SHADER]", "[TEXTURE]", "[RENDER]", "[VULKAN]", "[SCENE]",
-                        "[ENTITY]", "[ENGINE]", "[JOB]", "[STORAGE]", "[BUNDLE]",
-                        "[WINDOW]", "[SOUND]", "[AUDIO]", "[Item Filter]", "[HTTP2]",
-                        "[MESH]", "[MAT]", "[TRAILS]", "[GRAPH]", "[VIDEO]",
-                        "[PARTICLE]", "[RESOURCE]", "[STREAMLINE]", "@From ",
+                        "@From ", "@To ",
                         "User agent:", "Using backend:", "Web root:", "Queue :",
                         "family =", "Driver Version:", "Windows Version:", "OS:",
                         "Enabled:", "Result:", "Hash:", "count =", "flags =",
-                        "#", "&: GUILD UPDATE:", "Trade accepted", "Trade cancelled",
+                        "#", "&: GUILD UPDATE:", locale.trade_accepted, locale.trade_cancelled,
                         "Failed to apply item", "[WARN", "[CRIT", "[ERROR"
                     ])
-                    .custom(is_valid_npc_dialogue),
+                    .exclude_subsystems()
+                    .custom(|message, _| is_valid_npc_dialogue(message)),
             },
         ]
     }
 }
 
+/// Known subsystem tags (the `[TAG]` right after the log header) grouped by category
+/// Tags missing from this table still show up as their own subsystem on the event
+const SUBSYSTEM_TAGS: &[(&str, Category)] = &[
+    ("SHADER", Category::Graphics),
+    ("TEXTURE", Category::Graphics),
+    ("RENDER", Category::Graphics),
+    ("VULKAN", Category::Graphics),
+    ("SCENE", Category::Graphics),
+    ("MESH", Category::Graphics),
+    ("MAT", Category::Graphics),
+    ("TRAILS", Category::Graphics),
+    ("GRAPH", Category::Graphics),
+    ("VIDEO", Category::Graphics),
+    ("PARTICLE", Category::Graphics),
+    ("STREAMLINE", Category::Graphics),
+    ("ENTITY", Category::Engine),
+    ("ENGINE", Category::Engine),
+    ("JOB", Category::Engine),
+    ("STORAGE", Category::Engine),
+    ("BUNDLE", Category::Engine),
+    ("WINDOW", Category::Engine),
+    ("RESOURCE", Category::Engine),
+    ("SOUND", Category::Audio),
+    ("AUDIO", Category::Audio),
+    ("HTTP2", Category::Network),
+    ("Item Filter", Category::ItemFilter),
+];
+
+/// Drops "YYYY/MM/DD HH:MM:SS counter thread [LEVEL Source PID] " from a log line
+pub fn strip_header(line: &str) -> &str {
+    if let Some(header_start) = line.find('[') {
+        if let Some(header_len) = line[header_start..].find("] ") {
+            if line[header_start..header_start + header_len].contains(' ') {
+                return &line[header_start + header_len + 2..];
+            }
+        }
+    }
+    line
+}
+
+/// Text of a system message, i.e. what follows the ": " right after the log header
+pub fn system_text(message: &str) -> Option<&str> {
+    strip_header(message.lines().next()?).strip_prefix(": ")
+}
+
+/// True when `message` is a system line matching the whole locale template
+pub fn is_system_message(message: &str, template: &'static str) -> bool {
+    system_text(message).is_some_and(|text| locale::match_template(template, text).is_some())
+}
+
+/// Marker the client writes each time it starts and opens the log
+pub const SESSION_START_MARKER: &str = "***** LOG FILE OPENING *****";
+
+/// True for the line that begins a new client session
+pub fn is_session_start(message: &str) -> bool {
+    message.contains(SESSION_START_MARKER)
+}
+
+/// Extracts the subsystem tag that directly follows the log header
+/// Pattern: "... [INFO Client 7776] [SHADER] message" -> "SHADER"
+pub fn extract_subsystem(message: &str) -> Option<&str> {
+    let first_line = message.lines().next()?;
+
+    // The header bracket holds level, source and pid, e.g. "[INFO Client 7776]"
+    let header_start = first_line.find('[')?;
+    let header_len = first_line[header_start..].find("] ")?;
+    if !first_line[header_start..header_start + header_len].contains(' ') {
+        return None;
+    }
+
+    let after_header = &first_line[header_start + header_len + 2..];
+    let tag_body = after_header.strip_prefix('[')?;
+    let tag = &tag_body[..tag_body.find(']')?];
+
+    let valid = !tag.is_empty()
+        && tag.len() <= 32
+        && tag.starts_with(|c: char| c.is_ascii_alphabetic())
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '_' || c == '-');
+
+    if valid {
+        Some(tag)
+    } else {
+        None
+    }
+}
+
 /// Chat channel types for categorization
 #[derive(Debug, Clone, PartialEq)]
 pub enum ChatChannel {
-    Global,      // $ prefix - global/trade chat
-    Local,       // # prefix - local/area chat  
-    Guild,       // & prefix - guild player message
-    GuildSystem, // &: prefix - guild system announcement
-    Whisper,     // @From - whisper/trade interaction
-    Trade,       // Trade accepted/cancelled
+    Global,          // $ prefix - global/trade chat
+    Local,           // # prefix - local/area chat  
+    Party,           // % prefix - party chat
+    Guild,           // & prefix - guild player message
+    GuildSystem,     // &: prefix - guild system announcement
+    Whisper,         // @From - incoming whisper/trade interaction
+    WhisperOutgoing, // @To - whisper we sent
+    Trade,           // Trade accepted/cancelled, in any client language
 }
 
+impl ChatChannel {
+    /// Channel value sent to the frontend
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChatChannel::Global => "global",
+            ChatChannel::Local => "local",
+            ChatChannel::Party => "party",
+            ChatChannel::Guild => "guild",
+            ChatChannel::GuildSystem => "guild_system",
+            ChatChannel::Whisper => "whisper",
+            ChatChannel::WhisperOutgoing => "whisper_outgoing",
+            ChatChannel::Trade => "trade",
+        }
+    }
+}
+
+/// Prefixes the client writes in front of the sender of player chat
+/// "&: " guild announcements are checked before the plain "&" guild prefix
+pub const CHAT_PREFIXES: &[(char, ChatChannel)] = &[
+    ('$', ChatChannel::Global),
+    ('#', ChatChannel::Local),
+    ('%', ChatChannel::Party),
+    ('&', ChatChannel::Guild),
+];
+
 /// Detects if a message is a chat message and returns the channel type
-fn detect_chat_channel(message: &str) -> Option<ChatChannel> {
-    // Check for @From whispers
-    if message.contains("@From ") {
+pub fn detect_chat_channel(message: &str, locale: &LocalePack) -> Option<ChatChannel> {
+    // Extract the message part after the log header, so "] " inside chat text is ignored
+    let message_part = strip_header(message);
+
+    // Check for @From / @To whispers
+    if message_part.starts_with("@From ") {
         return Some(ChatChannel::Whisper);
     }
+
+    if message_part.starts_with("@To ") {
+        return Some(ChatChannel::WhisperOutgoing);
+    }
     
     // Check for trade actions
-    if message.contains("Trade accepted") || message.contains("Trade cancelled") {
+    if is_system_message(message, locale.trade_accepted)
+        || is_system_message(message, locale.trade_cancelled)
+    {
         return Some(ChatChannel::Trade);
     }
     
-    // Extract the message part after the log prefix (after "] ")
-    let message_part = if let Some(bracket_pos) = message.rfind("] ") {
-        &message[bracket_pos + 2..]
-    } else {
-        message
-    };
-    
-    // Check prefixes for different chat channels
-    if message_part.starts_with("$") && message_part.contains(": ") {
-        return Some(ChatChannel::Global);
-    }
-    
-    if message_part.starts_with("#") && message_part.contains(": ") {
-        return Some(ChatChannel::Local);
-    }
-    
     if message_part.starts_with("&: ") {
         return Some(ChatChannel::GuildSystem);
     }
     
-    if message_part.starts_with("&") && message_part.contains(": ") {
-        return Some(ChatChannel::Guild);
-    }
-    
-    None
+    // Check prefixes for different chat channels
+    CHAT_PREFIXES
+        .iter()
+        .find(|(prefix, _)| message_part.starts_with(*prefix) && message_part.contains(": "))
+        .map(|(_, channel)| channel.clone())
 }
 
-fn is_trade_or_chat_message(message: &str) -> bool {
-    detect_chat_channel(message).is_some()
+fn is_trade_or_chat_message(message: &str, locale: &LocalePack) -> bool {
+    detect_chat_channel(message, locale).is_some()
+}
+
+fn is_death_message(message: &str, locale: &LocalePack) -> bool {
+    is_system_message(message, locale.death)
+}
+
+fn is_level_up_message(message: &str, locale: &LocalePack) -> bool {
+    is_system_message(message, locale.level_up)
+}
+
+fn is_status_message(message: &str, locale: &LocalePack) -> bool {
+    [
+        locale.area_joined,
+        locale.area_left,
+        locale.status_on,
+        locale.status_off,
+        locale.played,
+        locale.character_age,
+        locale.never_died,
+        locale.died,
+        locale.passive_points,
+    ]
+    .iter()
+    .any(|template| is_system_message(message, template))
+}
+
+/// Rewards and passive allocations, which the client logs with or without the ": " prefix
+fn is_skill_message(message: &str, locale: &LocalePack) -> bool {
+    let body = strip_header(message.lines().next().unwrap_or(message));
+    let text = body.strip_prefix(": ").unwrap_or(body);
+    [
+        locale.item_received,
+        locale.passive_allocated,
+        locale.passive_unallocated,
+        locale.passives_reset,
+    ]
+    .iter()
+    .any(|template| locale::match_template(template, text).is_some())
 }
 
 /// Validates if a speaker name looks like a legitimate character/NPC name
@@ -378,6 +939,11 @@ fn is_valid_dialogue_text(text: &str) -> bool {
 /// Validates NPC dialogue using heuristic pattern detection
 /// No hardcoded character/NPC names - dynamically detects dialogue patterns
 fn is_valid_npc_dialogue(message: &str) -> bool {
+    // Tagged subsystem output is never dialogue, even for tags we don't know yet
+    if extract_subsystem(message).is_some() {
+        return false;
+    }
+
     // Extract the message part after the log prefix
     let message_part = if let Some(bracket_pos) = message.rfind("] ") {
         &message[bracket_pos + 2..]
@@ -386,9 +952,8 @@ fn is_valid_npc_dialogue(message: &str) -> bool {
     };
     
     // Skip if it looks like a chat message (already handled by Trade category)
-    if message_part.starts_with('$') || message_part.starts_with('#') || 
-       message_part.starts_with('&') || message_part.starts_with('@') ||
-       message_part.starts_with(':') {
+    if CHAT_PREFIXES.iter().any(|(prefix, _)| message_part.starts_with(*prefix)) ||
+       message_part.starts_with('@') || message_part.starts_with(':') {
         return false;
     }
     
@@ -404,4 +969,91 @@ fn is_valid_npc_dialogue(message: &str) -> bool {
     }
     
     false
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sample lines from research.md
+    const GLOBAL: &str = "2025/11/04 19:20:50 187967859 3ef232c2 [INFO Client 7776] $The_Abyss_Guy: BUYING RITUAL PRECUSOR TABLET (all mods except unique) : 2 Tablet = 1 Divine Orbs now)";
+    const LOCAL: &str = "2025/11/04 19:25:02 188219112 3ef232c2 [INFO Client 7776] #Julekuk: you were wrong";
+    const GUILD: &str = "2025/11/04 19:25:40 188257001 3ef232c2 [INFO Client 7776] &BrickToast: anyone on that can help";
+    const GUILD_SYSTEM: &str = "2025/11/04 19:25:41 188257002 3ef232c2 [INFO Client 7776] &: GUILD UPDATE: Final FTK is done for the season";
+    const PARTY: &str = "2025/11/04 19:25:42 188257003 3ef232c2 [INFO Client 7776] %BrickToast: portal is up";
+    const WHISPER_FROM: &str = "2025/11/04 19:25:43 188257004 3ef232c2 [INFO Client 7776] @From Kekius_Maxymus: Hi, I would like to buy your item";
+    const WHISPER_TO: &str = "2025/11/04 19:25:44 188257005 3ef232c2 [INFO Client 7776] @To Kekius_Maxymus: sure, invite sent";
+
+    #[test]
+    fn detects_every_chat_prefix() {
+        assert_eq!(detect_chat_channel(GLOBAL, &locale::ENGLISH), Some(ChatChannel::Global));
+        assert_eq!(detect_chat_channel(LOCAL, &locale::ENGLISH), Some(ChatChannel::Local));
+        assert_eq!(detect_chat_channel(GUILD, &locale::ENGLISH), Some(ChatChannel::Guild));
+        assert_eq!(detect_chat_channel(GUILD_SYSTEM, &locale::ENGLISH), Some(ChatChannel::GuildSystem));
+        assert_eq!(detect_chat_channel(PARTY, &locale::ENGLISH), Some(ChatChannel::Party));
+        assert_eq!(detect_chat_channel(WHISPER_FROM, &locale::ENGLISH), Some(ChatChannel::Whisper));
+        assert_eq!(detect_chat_channel(WHISPER_TO, &locale::ENGLISH), Some(ChatChannel::WhisperOutgoing));
+    }
+
+    #[test]
+    fn ignores_non_chat_lines() {
+        let dialogue = "2025/11/04 19:26:11 188288310 3ef232c2 [INFO Client 7776] Siora, Blade of the Mists: The King in the Mists claims this wood.";
+        let death = "2025/11/04 19:22:13 188051531 3ef232c2 [INFO Client 7776] : TomHanksIndexFinger has been slain.";
+        assert_eq!(detect_chat_channel(dialogue, &locale::ENGLISH), None);
+        assert_eq!(detect_chat_channel(death, &locale::ENGLISH), None);
+    }
+
+    #[test]
+    fn chat_lines_are_not_dialogue() {
+        let categorizer = LogCategorizer::new();
+        for line in [GLOBAL, LOCAL, PARTY, WHISPER_FROM, WHISPER_TO] {
+            assert_eq!(categorizer.categorize(line, line), Some(Category::Trade), "{}", line);
+        }
+        assert_eq!(categorizer.categorize(GUILD_SYSTEM, GUILD_SYSTEM), Some(Category::Trade));
+    }
+
+    #[test]
+    fn gameplay_wording_inside_dialogue_stays_dialogue() {
+        let categorizer = LogCategorizer::new();
+        let received = "2025/11/04 19:26:12 188288311 3ef232c2 [INFO Client 7776] Einar: You have received it, Exile.";
+        let slain = "2025/11/04 19:26:13 188288312 3ef232c2 [INFO Client 7776] Einar: The beast has been slain.";
+        assert_eq!(categorizer.categorize(received, received), Some(Category::Dialogue));
+        assert_eq!(categorizer.categorize(slain, slain), Some(Category::Dialogue));
+
+        let death = "2025/11/04 19:22:13 188051531 3ef232c2 [INFO Client 7776] : TomHanksIndexFinger has been slain.";
+        assert_eq!(categorizer.categorize(death, death), Some(Category::Death));
+    }
+
+    #[test]
+    fn category_names_round_trip_and_unknown_names_are_rejected() {
+        for category in LogCategorizer::new().list_categories() {
+            let json = serde_json::to_string(&category.name).unwrap();
+            assert_eq!(serde_json::from_str::<Category>(&json).unwrap(), category.name);
+        }
+        assert!(serde_json::from_str::<Category>("\"Crafting\"").is_err());
+    }
+
+    #[test]
+    fn status_lines_get_their_own_category() {
+        let categorizer = LogCategorizer::new();
+        let header = "2025/11/04 19:30:00 188500000 3ef232c2 [INFO Client 7776] ";
+        let lines = [
+            ": BrickToast has joined the area.",
+            ": BrickToast has left the area.",
+            ": AFK mode is now ON. Autoreply \"This player is AFK.\"",
+            ": AFK mode is now OFF.",
+            ": You have played for 2 days, 3 hours, 4 minutes and 5 seconds.",
+            ": Your character was created 5 days, 1 hour and 2 minutes ago.",
+            ": You have never died.",
+            ": You have died 12 times.",
+            ": You have 22 Passive Skill Points from quests.",
+        ];
+        for body in lines {
+            let line = format!("{}{}", header, body);
+            assert_eq!(categorizer.categorize(&line, &line), Some(Category::Status), "{}", body);
+        }
+
+        let quoted = format!("{}#Julekuk: BrickToast has joined the area.", header);
+        assert_ne!(categorizer.categorize(&quoted, &quoted), Some(Category::Status));
+    }
+}
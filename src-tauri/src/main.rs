@@ -3,9 +3,50 @@
     windows_subsystem = "windows"
 )]
 
+mod character_roster;
+mod coverage;
+mod instance_tracker;
+mod item_filter;
+mod locale;
 mod log_categorizer;
+mod mute_list;
+mod network_stats;
+mod passive_tracker;
+mod persistence;
+mod player_status;
+mod presence;
+mod rewards;
+mod slash_commands;
+mod system_profile;
+mod template_miner;
+mod timestamp;
+mod trade_tracker;
+mod trade_whisper;
+mod zone_tracker;
 
-use log_categorizer::LogCategorizer;
+use character_roster::{
+    AscendancyMapping, Ascension, Character, CharacterRoster, CHARACTER_ROSTER_FILE,
+};
+use coverage::{CoverageReport, CoverageTracker};
+use locale::LocalePack;
+use log_categorizer::{
+    extract_subsystem, is_session_start, is_system_message, strip_header, CategorizationExplanation, Category, CategoryInfo, LogCategorizer,
+    CHAT_PREFIXES,
+};
+use mute_list::{MuteAction, MuteList, MUTE_LIST_FILE};
+use instance_tracker::{InstanceConnection, InstanceServerSummary, InstanceTracker};
+use item_filter::{parse_item_filter_event, FilterHistoryEntry, ItemFilterEvent, ItemFilterHistory};
+use network_stats::{parse_latency, LatencyReport, LatencySample, LatencyTracker};
+use passive_tracker::{parse_passive_change, PassiveChange, PassiveTracker, PassiveTree};
+use rewards::{parse_reward, Reward, RewardLog};
+use slash_commands::{parse_command_result, CharacterStats, CharacterStatsTracker, CommandResult};
+use system_profile::{parse_profile_line, SystemProfile, SystemProfileTracker};
+use player_status::{parse_status_change, PlayerStatus, StatusChange, StatusTracker};
+use presence::{parse_presence, PresenceEvent, PresenceTracker};
+use template_miner::{LogTemplate, TemplateMiner};
+use trade_tracker::{Trade, TradeTracker};
+use trade_whisper::{parse_trade_request, TradeRequest};
+use zone_tracker::{ZoneChange, ZoneTracker};
 use serde::Serialize;
 use std::{
     fs::File,
@@ -16,14 +57,17 @@ use std::{
     collections::hash_map::DefaultHasher,
     hash::{Hash, Hasher},
 };
-use tauri::{Emitter, State};
+use tauri::{Emitter, Manager, State};
 
 #[derive(Clone, Serialize, Debug)]
 struct LogEvent {
     timestamp: String,
-    category: String,
+    category: Category,
     message: String,
     raw: String,
+    // Bracketed tag after the log header, e.g. "SHADER" or "Item Filter"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subsystem: Option<String>,
     // Optional extracted fields for enhanced display
     #[serde(skip_serializing_if = "Option::is_none")]
     player_name: Option<String>,
@@ -35,138 +79,241 @@ struct LogEvent {
     chat_sender: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     chat_channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chat_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chat_counterpart: Option<String>,
+    // "incoming" or "outgoing" for whispers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<String>,
+    // Output of /played, /age, /deaths or /passives
+    #[serde(skip_serializing_if = "Option::is_none")]
+    command_result: Option<CommandResult>,
+    // AFK or DND switched on or off
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status_change: Option<StatusChange>,
+    // Outgoing whisper sent by the client as the AFK/DND auto-reply
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    auto_reply: bool,
+    // Player joining or leaving our instance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presence: Option<PresenceEvent>,
+    // Instance server address on the line that connects to it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance_server: Option<String>,
+    // Host, region and time of a connection line that reports latency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latency: Option<LatencySample>,
+    // Filter id, hash or download outcome from an `[Item Filter]` line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item_filter: Option<ItemFilterEvent>,
+    // Quest reward, skill points or item the character received
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reward: Option<Reward>,
+    // Passive node allocated, refunded or full tree reset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    passive_change: Option<PassiveChange>,
+    // Own character a passive, reward or slash command line was attributed to;
+    // absent on such lines when no character was known yet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    character: Option<String>,
+    // Parsed trade-site purchase whisper
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trade_request: Option<TradeRequest>,
+    // Mined message structure and the values of its wildcard tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template_id: Option<u32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    template_params: Vec<String>,
+    // Area the player was in when the line was written
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zone: Option<String>,
+    // Set on the line that moved the player into a new area
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zone_change: Option<ZoneChange>,
+    // Sender is on the mute list and the list is set to flag rather than drop
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    muted: bool,
+    // No category rule matched and the fallback category was used
+    #[serde(skip)]
+    fallback: bool,
+    // Trades this entry moved forward, emitted separately as `trade_updated`
+    #[serde(skip)]
+    trade_updates: Vec<Trade>,
+    // Roster entries this entry changed, emitted separately as `character_updated`
+    #[serde(skip)]
+    character_updates: Vec<Character>,
+    // Ascendancy reached on this level-up, emitted separately as `ascended`
+    #[serde(skip)]
+    ascension: Option<Ascension>,
 }
 
 /// Extracts player name from death messages
-/// Pattern: ": PlayerName has been slain."
-fn extract_death_info(message: &str) -> Option<String> {
+/// Pattern: ": PlayerName has been slain." (or the locale's equivalent)
+fn extract_death_info(message: &str, locale: &LocalePack) -> Option<String> {
     // Look for the pattern after the log prefix
     let message_part = if let Some(bracket_pos) = message.rfind("] ") {
         &message[bracket_pos + 2..]
     } else {
         message
     };
-    
+
     // Death messages start with ": " followed by player name
-    if message_part.starts_with(": ") && message_part.contains(" has been slain") {
-        let content = &message_part[2..]; // Skip ": "
-        if let Some(end_pos) = content.find(" has been slain") {
-            let player_name = &content[..end_pos];
-            if !player_name.is_empty() && player_name.chars().all(|c| c.is_alphanumeric() || c == '_') {
-                return Some(player_name.to_string());
-            }
-        }
+    let content = message_part.strip_prefix(": ")?;
+    let values = locale::match_template(locale.death, content)?;
+    let player_name = locale::field(&values, "name")?.trim();
+
+    // `is_alphanumeric` is Unicode-aware, so Cyrillic, Hangul or CJK names pass too
+    if !player_name.is_empty() && player_name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Some(player_name.to_string());
     }
     None
 }
 
 /// Extracts character info from level-up messages
-/// Pattern: ": CharName (ClassName) is now level N"
+/// Pattern: ": CharName (ClassName) is now level N" (or the locale's equivalent)
 /// Returns: (character_name, class_name, level_number)
-fn extract_level_up_info(message: &str) -> Option<(String, String, u32)> {
+fn extract_level_up_info(message: &str, locale: &LocalePack) -> Option<(String, String, u32)> {
     // Look for the pattern after the log prefix
     let message_part = if let Some(bracket_pos) = message.rfind("] ") {
         &message[bracket_pos + 2..]
     } else {
         message
     };
-    
+
     // Level-up messages start with ": " followed by character info
-    if message_part.starts_with(": ") && message_part.contains(" is now level ") {
-        let content = &message_part[2..]; // Skip ": "
-        
-        // Find character name (before the parenthesis)
-        if let Some(paren_start) = content.find(" (") {
-            let char_name = &content[..paren_start];
-            
-            // Find class name (inside parentheses)
-            if let Some(paren_end) = content.find(") is now level ") {
-                let class_name = &content[paren_start + 2..paren_end];
-                
-                // Find level number
-                let level_start = paren_end + ") is now level ".len();
-                let level_str: String = content[level_start..]
-                    .chars()
-                    .take_while(|c| c.is_ascii_digit())
-                    .collect();
-                
-                if let Ok(level) = level_str.parse::<u32>() {
-                    if !char_name.is_empty() && !class_name.is_empty() {
-                        return Some((char_name.to_string(), class_name.to_string(), level));
-                    }
-                }
-            }
+    let content = message_part.strip_prefix(": ")?;
+    let values = locale::match_template(locale.level_up, content)?;
+
+    let char_name = locale::field(&values, "name")?.trim();
+    let class_name = locale::field(&values, "class")?.trim();
+    let level_str: String = locale::field(&values, "level")?
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+
+    if let Ok(level) = level_str.parse::<u32>() {
+        if !char_name.is_empty() && !class_name.is_empty() {
+            return Some((char_name.to_string(), class_name.to_string(), level));
         }
     }
     None
 }
 
+/// Chat details pulled from a chat or trade line
+struct ChatInfo {
+    sender: Option<String>,
+    channel: String,
+    // What the player typed, without header, channel prefix or sender
+    text: Option<String>,
+    // Other party of a whisper, whichever way it went
+    counterpart: Option<String>,
+    direction: Option<&'static str>,
+}
+
+impl ChatInfo {
+    fn new(sender: Option<String>, channel: &str, text: Option<&str>) -> Self {
+        Self {
+            sender,
+            channel: channel.to_string(),
+            text: text.map(|text| text.trim().to_string()),
+            counterpart: None,
+            direction: None,
+        }
+    }
+}
+
+/// Splits a whisper into the other party and the typed text
+/// Pattern: "@From <GUILD> Name: message" -> ("Name", "message")
+fn extract_whisper(message_part: &str, prefix: &str) -> (Option<String>, Option<String>) {
+    let after_prefix = match message_part.strip_prefix(prefix) {
+        Some(after_prefix) => after_prefix,
+        None => return (None, None),
+    };
+    let colon_pos = match after_prefix.find(':') {
+        Some(pos) => pos,
+        None => return (None, None),
+    };
+
+    // Drop the guild tag the client puts in front of the name
+    let name = &after_prefix[..colon_pos];
+    let name = name
+        .strip_prefix('<')
+        .and_then(|tagged| tagged.find("> ").map(|end| &tagged[end + 2..]))
+        .unwrap_or(name)
+        .trim();
+
+    let text = after_prefix[colon_pos + 1..].trim();
+
+    (
+        Some(name.to_string()).filter(|name| !name.is_empty()),
+        Some(text.to_string()).filter(|text| !text.is_empty()),
+    )
+}
+
 /// Extracts sender name and channel from chat messages
 /// Patterns: 
+///   "@From SenderName: message" -> ("SenderName", "whisper", incoming)
+///   "@To RecipientName: message" -> (None, "whisper_outgoing", outgoing)
 ///   "$SenderName: message" -> ("SenderName", "global")
 ///   "#SenderName: message" -> ("SenderName", "local")  
+///   "%SenderName: message" -> ("SenderName", "party")
 ///   "&SenderName: message" -> ("SenderName", "guild")
 ///   "&: SYSTEM_MESSAGE" -> (None, "guild_system")
-/// Returns: (sender_name, channel_type)
-fn extract_chat_info(message: &str) -> Option<(Option<String>, String)> {
-    // Look for the message part after the log prefix
-    let message_part = if let Some(bracket_pos) = message.rfind("] ") {
-        &message[bracket_pos + 2..]
-    } else {
-        message
-    };
+fn extract_chat_info(message: &str, locale: &LocalePack) -> Option<ChatInfo> {
+    // Look for the message part after the log header
+    let message_part = strip_header(message);
     
-    // Check for @From whispers
-    if message.contains("@From ") {
-        // Pattern: "@From SenderName: message"
-        if let Some(from_pos) = message.find("@From ") {
-            let after_from = &message[from_pos + 6..];
-            if let Some(colon_pos) = after_from.find(':') {
-                let sender = &after_from[..colon_pos];
-                if !sender.is_empty() {
-                    return Some((Some(sender.to_string()), "whisper".to_string()));
-                }
-            }
-        }
-        return Some((None, "whisper".to_string()));
+    // Incoming whispers: "@From SenderName: message"
+    if message_part.starts_with("@From ") {
+        let (counterpart, text) = extract_whisper(message_part, "@From ");
+        return Some(ChatInfo {
+            sender: counterpart.clone(),
+            channel: "whisper".to_string(),
+            text,
+            counterpart,
+            direction: Some("incoming"),
+        });
+    }
+
+    // Outgoing whispers: "@To RecipientName: message"
+    if message_part.starts_with("@To ") {
+        let (counterpart, text) = extract_whisper(message_part, "@To ");
+        return Some(ChatInfo {
+            sender: None,
+            channel: "whisper_outgoing".to_string(),
+            text,
+            counterpart,
+            direction: Some("outgoing"),
+        });
     }
     
     // Trade actions
-    if message.contains("Trade accepted") || message.contains("Trade cancelled") {
-        return Some((None, "trade".to_string()));
+    if is_system_message(message, locale.trade_accepted)
+        || is_system_message(message, locale.trade_cancelled)
+    {
+        return Some(ChatInfo::new(None, "trade", None));
     }
     
     // Guild system messages: "&: MESSAGE"
-    if message_part.starts_with("&: ") {
-        return Some((None, "guild_system".to_string()));
+    if let Some(announcement) = message_part.strip_prefix("&: ") {
+        return Some(ChatInfo::new(None, "guild_system", Some(announcement)));
     }
     
-    // Global chat: "$SenderName: message"
-    if message_part.starts_with('$') {
-        if let Some(colon_pos) = message_part.find(": ") {
-            let sender = &message_part[1..colon_pos];
-            if !sender.is_empty() {
-                return Some((Some(sender.to_string()), "global".to_string()));
-            }
-        }
-    }
-    
-    // Local chat: "#SenderName: message"
-    if message_part.starts_with('#') {
-        if let Some(colon_pos) = message_part.find(": ") {
-            let sender = &message_part[1..colon_pos];
-            if !sender.is_empty() {
-                return Some((Some(sender.to_string()), "local".to_string()));
-            }
-        }
-    }
-    
-    // Guild chat: "&SenderName: message"
-    if message_part.starts_with('&') && !message_part.starts_with("&: ") {
-        if let Some(colon_pos) = message_part.find(": ") {
-            let sender = &message_part[1..colon_pos];
-            if !sender.is_empty() {
-                return Some((Some(sender.to_string()), "guild".to_string()));
+    // Player chat: "$SenderName: message", "%SenderName: message", ...
+    for (prefix, channel) in CHAT_PREFIXES {
+        if let Some(after_prefix) = message_part.strip_prefix(*prefix) {
+            if let Some(colon_pos) = after_prefix.find(": ") {
+                let sender = &after_prefix[..colon_pos];
+                let text = &after_prefix[colon_pos + 2..];
+                if !sender.is_empty() {
+                    return Some(ChatInfo::new(
+                        Some(sender.to_string()),
+                        channel.as_str(),
+                        Some(text),
+                    ));
+                }
             }
         }
     }
@@ -180,6 +327,38 @@ struct AppState {
     is_watching: bool,
     processed_entries: std::collections::HashSet<u64>,
     categorizer: Option<LogCategorizer>,
+    coverage: CoverageTracker,
+    templates: TemplateMiner,
+    // App data directory for persisted settings, set during setup
+    data_dir: Option<PathBuf>,
+    mute_list: MuteList,
+    zones: ZoneTracker,
+    trades: TradeTracker,
+    passives: PassiveTracker,
+    item_filters: ItemFilterHistory,
+    latency: LatencyTracker,
+    instances: InstanceTracker,
+    profiles: SystemProfileTracker,
+    presence: PresenceTracker,
+    status: StatusTracker,
+    character_stats: CharacterStatsTracker,
+    rewards: RewardLog,
+    // Own character that last levelled up, or the only one in the roster; passive,
+    // slash command and reward lines don't name the character, so they are attributed
+    // to this one. Party members level up in the same log, so only names marked as
+    // the player's own switch it, and a character that hasn't levelled up since
+    // being played can't be told apart from the previous one.
+    current_character: Option<String>,
+    // Persisted, so it survives restarts and isn't cleared with the other trackers
+    roster: CharacterRoster,
+}
+
+impl AppState {
+    /// Character a command asked for, or the current one when `character` is None
+    /// None when neither is known, which selects the unattributed lines
+    fn character_key<'a>(&'a self, character: Option<&'a str>) -> Option<&'a str> {
+        character.or(self.current_character.as_deref())
+    }
 }
 
 type SafeAppState = Arc<Mutex<AppState>>;
@@ -204,6 +383,24 @@ async fn start_watching(
         app_state.current_file = Some(log_path.clone());
         app_state.is_watching = true;
         app_state.processed_entries.clear();
+        app_state.coverage.clear();
+        app_state.templates.clear();
+        app_state.zones.clear();
+        app_state.trades.clear();
+        app_state.passives.clear();
+        app_state.item_filters.clear();
+        app_state.latency.clear();
+        app_state.instances.clear();
+        app_state.profiles.clear();
+        app_state.presence.clear();
+        app_state.status.clear();
+        app_state.character_stats.clear();
+        app_state.rewards.clear();
+        // With a single own character every unnamed line is theirs, even before it levels up
+        app_state.current_character = match app_state.roster.characters.as_slice() {
+            [only] => Some(only.name.clone()),
+            _ => None,
+        };
         
         if app_state.categorizer.is_none() {
             app_state.categorizer = Some(LogCategorizer::new());
@@ -214,9 +411,7 @@ async fn start_watching(
         Ok(existing_logs) => {
             println!("Found {} existing log entries", existing_logs.len());
             for log in existing_logs {
-                if let Err(e) = app.emit("log_event", &log) {
-                    eprintln!("Failed to emit existing log: {}", e);
-                }
+                emit_log_event(&app, &log);
             }
         }
         Err(e) => {
@@ -255,6 +450,360 @@ async fn open_url(url: String) -> Result<(), String> {
     Ok(())
 }
 
+#[derive(Serialize)]
+struct ExplainResult {
+    #[serde(flatten)]
+    explanation: CategorizationExplanation,
+    event: LogEvent,
+}
+
+#[tauri::command]
+async fn explain_categorization(
+    text: String,
+    state: State<'_, SafeAppState>,
+) -> Result<ExplainResult, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    let fallback;
+    let categorizer = match app_state.categorizer.as_ref() {
+        Some(categorizer) => categorizer,
+        None => {
+            fallback = LogCategorizer::new();
+            &fallback
+        }
+    };
+
+    let first_line = text.lines().next().unwrap_or_default();
+    Ok(ExplainResult {
+        explanation: categorizer.explain(&text),
+        event: build_log_event(&text, first_line, Some(categorizer)),
+    })
+}
+
+#[tauri::command]
+async fn list_categories(state: State<'_, SafeAppState>) -> Result<Vec<CategoryInfo>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(match app_state.categorizer.as_ref() {
+        Some(categorizer) => categorizer.list_categories(),
+        None => LogCategorizer::new().list_categories(),
+    })
+}
+
+/// Uncategorized messages grouped by template, most frequent first
+#[tauri::command]
+async fn coverage_report(
+    limit: Option<usize>,
+    state: State<'_, SafeAppState>,
+) -> Result<CoverageReport, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.coverage.report(limit))
+}
+
+/// Mined message templates, most frequent first
+#[tauri::command]
+async fn list_templates(
+    limit: Option<usize>,
+    state: State<'_, SafeAppState>,
+) -> Result<Vec<LogTemplate>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    let mut templates = app_state.templates.templates();
+    if let Some(limit) = limit {
+        templates.truncate(limit);
+    }
+    Ok(templates)
+}
+
+#[tauri::command]
+async fn get_current_zone(state: State<'_, SafeAppState>) -> Result<Option<ZoneChange>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.zones.current().cloned())
+}
+
+/// All trades, newest first
+/// Open trades are first timed out as of `now` ("YYYY/MM/DD HH:MM:SS" local time), or as
+/// of the latest log entry when omitted, so they close even while the log is quiet
+#[tauri::command]
+async fn list_trades(now: Option<String>, state: State<'_, SafeAppState>) -> Result<Vec<Trade>, String> {
+    let mut app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    app_state.trades.expire_as_of(now.as_deref());
+    Ok(app_state.trades.trades())
+}
+
+/// Passive changes of `character`, or of the current character when omitted
+/// Changes are attributed to the own character that last levelled up, so those made on
+/// a character that hasn't levelled up this session land on the previous one; with no
+/// character known yet they are returned unattributed for an omitted `character`.
+#[tauri::command]
+async fn get_passive_history(
+    character: Option<String>,
+    state: State<'_, SafeAppState>,
+) -> Result<Vec<PassiveChange>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.passives.history(app_state.character_key(character.as_deref())))
+}
+
+/// Allocated passive nodes as of `at`, or now when omitted
+/// `character` is resolved as in `get_passive_history`
+#[tauri::command]
+async fn get_passive_tree(
+    character: Option<String>,
+    at: Option<String>,
+    state: State<'_, SafeAppState>,
+) -> Result<PassiveTree, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state
+        .passives
+        .tree_at(app_state.character_key(character.as_deref()), at.as_deref()))
+}
+
+/// Online item filter downloads and the hash each reported, newest first
+#[tauri::command]
+async fn get_item_filter_history(
+    state: State<'_, SafeAppState>,
+) -> Result<Vec<FilterHistoryEntry>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.item_filters.entries())
+}
+
+/// Min/median/p95 connection latency per server and per client session
+#[tauri::command]
+async fn latency_stats(state: State<'_, SafeAppState>) -> Result<LatencyReport, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.latency.report())
+}
+
+#[tauri::command]
+async fn latency_samples(
+    host: Option<String>,
+    state: State<'_, SafeAppState>,
+) -> Result<Vec<LatencySample>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.latency.samples(host.as_deref()))
+}
+
+#[tauri::command]
+async fn list_instances(
+    limit: Option<usize>,
+    state: State<'_, SafeAppState>,
+) -> Result<Vec<InstanceConnection>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    let mut connections = app_state.instances.connections();
+    if let Some(limit) = limit {
+        connections.truncate(limit);
+    }
+    Ok(connections)
+}
+
+/// Instance servers with their connect times and hosted areas, slowest first
+#[tauri::command]
+async fn instance_servers(
+    state: State<'_, SafeAppState>,
+) -> Result<Vec<InstanceServerSummary>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.instances.servers())
+}
+
+/// GPU, driver, OS and render backend of a session, the latest when omitted
+#[tauri::command]
+async fn get_system_profile(
+    session: Option<usize>,
+    state: State<'_, SafeAppState>,
+) -> Result<Option<SystemProfile>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.profiles.profile(session))
+}
+
+/// Players currently in our instance
+#[tauri::command]
+async fn get_roster(state: State<'_, SafeAppState>) -> Result<Vec<String>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.presence.roster())
+}
+
+#[tauri::command]
+async fn presence_history(
+    zone: Option<String>,
+    state: State<'_, SafeAppState>,
+) -> Result<Vec<PresenceEvent>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.presence.history(zone.as_deref()))
+}
+
+#[tauri::command]
+async fn get_player_status(state: State<'_, SafeAppState>) -> Result<PlayerStatus, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.status.status())
+}
+
+/// Latest /played, /age, /deaths and /passives values of a character
+/// `character` is resolved as in `get_passive_history`
+#[tauri::command]
+async fn get_character_stats(
+    character: Option<String>,
+    state: State<'_, SafeAppState>,
+) -> Result<Option<CharacterStats>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.character_stats.stats(app_state.character_key(character.as_deref())))
+}
+
+/// Rewards received by a character, resolved as in `get_passive_history`
+#[tauri::command]
+async fn list_rewards(
+    character: Option<String>,
+    state: State<'_, SafeAppState>,
+) -> Result<Vec<Reward>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.rewards.rewards(app_state.character_key(character.as_deref())))
+}
+
+/// The player's own characters, most recently seen first
+#[tauri::command]
+async fn list_characters(state: State<'_, SafeAppState>) -> Result<Vec<Character>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.roster.characters())
+}
+
+/// Other characters seen levelling up since launch, e.g. party members
+#[tauri::command]
+async fn list_other_characters(state: State<'_, SafeAppState>) -> Result<Vec<Character>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.roster.others())
+}
+
+/// Marks a character seen levelling up as the player's own, so the roster keeps it
+#[tauri::command]
+async fn set_own_character(
+    name: String,
+    own: bool,
+    state: State<'_, SafeAppState>,
+) -> Result<Character, String> {
+    let mut app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    let character = app_state.roster.set_own(&name, own)?;
+    if let Some(data_dir) = app_state.data_dir.as_ref() {
+        persistence::save_json(data_dir, CHARACTER_ROSTER_FILE, &app_state.roster)?;
+    }
+    Ok(character)
+}
+
+/// Base-class to ascendancy pairs seen so far
+#[tauri::command]
+async fn list_ascendancies(
+    state: State<'_, SafeAppState>,
+) -> Result<Vec<AscendancyMapping>, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.roster.ascendancies.clone())
+}
+
+#[tauri::command]
+async fn get_mute_list(state: State<'_, SafeAppState>) -> Result<MuteList, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state.mute_list.clone())
+}
+
+#[tauri::command]
+async fn mute_sender(name: String, state: State<'_, SafeAppState>) -> Result<MuteList, String> {
+    update_mute_list(&state, |list| {
+        list.add_name(&name);
+    })
+}
+
+#[tauri::command]
+async fn unmute_sender(name: String, state: State<'_, SafeAppState>) -> Result<MuteList, String> {
+    update_mute_list(&state, |list| {
+        list.remove_name(&name);
+    })
+}
+
+#[tauri::command]
+async fn add_mute_pattern(
+    pattern: String,
+    state: State<'_, SafeAppState>,
+) -> Result<MuteList, String> {
+    update_mute_list(&state, |list| {
+        list.add_pattern(&pattern);
+    })
+}
+
+#[tauri::command]
+async fn remove_mute_pattern(
+    pattern: String,
+    state: State<'_, SafeAppState>,
+) -> Result<MuteList, String> {
+    update_mute_list(&state, |list| {
+        list.remove_pattern(&pattern);
+    })
+}
+
+#[tauri::command]
+async fn set_mute_action(
+    action: MuteAction,
+    state: State<'_, SafeAppState>,
+) -> Result<MuteList, String> {
+    update_mute_list(&state, |list| list.action = action)
+}
+
+/// Applies a change to the mute list and persists it
+fn update_mute_list(
+    state: &SafeAppState,
+    change: impl FnOnce(&mut MuteList),
+) -> Result<MuteList, String> {
+    let mut app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    change(&mut app_state.mute_list);
+    if let Some(data_dir) = app_state.data_dir.as_ref() {
+        persistence::save_json(data_dir, MUTE_LIST_FILE, &app_state.mute_list)?;
+    }
+    Ok(app_state.mute_list.clone())
+}
+
 fn read_existing_logs(
     log_path: &PathBuf,
     state: SafeAppState,
@@ -333,9 +882,7 @@ async fn watch_log_file(
                 if is_timestamp_line(&trimmed) {
                     if !current_entry_lines.is_empty() {
                         if let Some(entry) = process_log_entry(&current_entry_lines, &state) {
-                            if let Err(e) = app.emit("log_event", &entry) {
-                                eprintln!("Failed to emit log event: {}", e);
-                            }
+                            emit_log_event(&app, &entry);
                         }
                         current_entry_lines.clear();
                     }
@@ -356,6 +903,28 @@ async fn watch_log_file(
     Ok(())
 }
 
+/// Sends an entry to the frontend along with the trade, character and ascension updates it caused
+fn emit_log_event(app: &tauri::AppHandle, entry: &LogEvent) {
+    if let Err(e) = app.emit("log_event", entry) {
+        eprintln!("Failed to emit log event: {}", e);
+    }
+    for trade in &entry.trade_updates {
+        if let Err(e) = app.emit("trade_updated", trade) {
+            eprintln!("Failed to emit trade update: {}", e);
+        }
+    }
+    for character in &entry.character_updates {
+        if let Err(e) = app.emit("character_updated", character) {
+            eprintln!("Failed to emit character update: {}", e);
+        }
+    }
+    if let Some(ascension) = &entry.ascension {
+        if let Err(e) = app.emit("ascended", ascension) {
+            eprintln!("Failed to emit ascension: {}", e);
+        }
+    }
+}
+
 fn is_timestamp_line(line: &str) -> bool {
     line.len() >= 19
         && line.chars().nth(4) == Some('/')
@@ -380,56 +949,240 @@ fn process_log_entry(lines: &[String], state: &SafeAppState) -> Option<LogEvent>
         }
         app_state.processed_entries.insert(entry_hash);
 
-        let timestamp = if first_line.len() >= 19 {
-            first_line.chars().take(19).collect()
-        } else {
-            String::new()
+        let mut event = build_log_event(&full_message, first_line, app_state.categorizer.as_ref());
+
+        // Muted senders are decided first, so dropped lines never reach the trackers
+        if let Some(sender) = event.chat_sender.as_deref() {
+            if app_state.mute_list.is_muted(sender) {
+                match app_state.mute_list.action {
+                    MuteAction::Drop => return None,
+                    MuteAction::Flag => event.muted = true,
+                }
+            }
+        }
+
+        app_state.coverage.record(&full_message, event.fallback);
+
+        let (template_id, template_params) = app_state.templates.add(&full_message, &event.timestamp);
+        event.template_id = Some(template_id);
+        event.template_params = template_params;
+
+        event.zone_change = app_state.zones.observe(&full_message, &event.timestamp);
+        event.zone = app_state.zones.current().map(|zone| zone.area_name.clone());
+        if event.zone_change.is_some() {
+            app_state.presence.zone_changed();
+        }
+
+        let roster_update = match (&event.player_name, &event.character_class, event.level) {
+            (Some(name), Some(class), Some(level)) => app_state
+                .roster
+                .level_up(name, class, level, event.zone.as_deref(), &event.timestamp)
+                .map(|(character, ascension)| {
+                    event.ascension = ascension;
+                    character
+                }),
+            (Some(name), None, None) => app_state.roster.death(name, &event.timestamp),
+            _ => None,
         };
+        if let Some(character) = roster_update {
+            if let Some(data_dir) = app_state.data_dir.as_ref() {
+                if let Err(e) = persistence::save_json(data_dir, CHARACTER_ROSTER_FILE, &app_state.roster) {
+                    eprintln!("Failed to save character roster: {}", e);
+                }
+            }
+            event.character_updates.push(character);
+        }
 
-        let category = if let Some(ref categorizer) = app_state.categorizer {
-            categorizer.categorize(&full_message, first_line)
-        } else {
-            "System".to_string()
+        if let (Some(name), Some(_)) = (&event.player_name, event.level) {
+            if app_state.roster.is_own(name) {
+                app_state.current_character = Some(name.clone());
+            }
+        }
+        let character = app_state.current_character.clone();
+        if let Some(change) = &event.passive_change {
+            app_state.passives.record(character.as_deref(), change.clone());
+            event.character = character.clone();
+        }
+        if let Some(reward) = &mut event.reward {
+            reward.zone = event.zone.clone();
+            app_state.rewards.record(character.as_deref(), reward.clone());
+            event.character = character.clone();
+        }
+        if is_session_start(&full_message) {
+            app_state.latency.start_session(&event.timestamp);
+            app_state.profiles.start_session(&event.timestamp);
+            app_state.roster.start_session(&event.timestamp);
+            app_state.status.start_session();
+            app_state.item_filters.start_session();
+        }
+        if let Some((field, value)) = parse_profile_line(&full_message) {
+            app_state.profiles.record(field, value, &event.timestamp);
+        }
+        if let Some(sample) = &event.latency {
+            app_state.latency.record(sample.clone());
+        }
+        event.instance_server = app_state.instances.observe(
+            &full_message,
+            &event.timestamp,
+            event.latency.as_ref(),
+            event.zone_change.as_ref(),
+        );
+        if let Some(filter_event) = &event.item_filter {
+            app_state.item_filters.record(filter_event);
+        }
+
+        let locale = app_state
+            .categorizer
+            .as_ref()
+            .map_or(&locale::ENGLISH, |categorizer| categorizer.locale());
+        if let Some((action, name)) = parse_presence(&full_message, locale) {
+            let presence = PresenceEvent {
+                name: name.to_string(),
+                action,
+                zone: event.zone.clone(),
+                timestamp: event.timestamp.clone(),
+            };
+            app_state.presence.record(presence.clone());
+            event.presence = Some(presence);
+        }
+
+        event.command_result = parse_command_result(&full_message, locale, &event.timestamp);
+        if let Some(result) = &event.command_result {
+            app_state.character_stats.record(character.as_deref(), result);
+            event.character = character;
+        }
+
+        event.status_change = parse_status_change(&full_message, locale, &event.timestamp);
+        if let Some(change) = &event.status_change {
+            app_state.status.record(change);
+        }
+        if event.direction.as_deref() == Some("outgoing") {
+            event.auto_reply = event
+                .chat_text
+                .as_deref()
+                .is_some_and(|text| app_state.status.is_auto_reply(text));
+        }
+
+        let request = match (&event.direction, &event.chat_counterpart, &event.trade_request) {
+            (Some(direction), Some(buyer), Some(request)) if direction == "incoming" => {
+                Some((buyer.as_str(), request))
+            }
+            _ => None,
         };
+        event.trade_updates =
+            app_state
+                .trades
+                .observe(&full_message, &event.timestamp, locale, request);
+
+        Some(event)
+    }
+}
+
+/// Categorizes an entry and extracts the category-specific fields
+fn build_log_event(
+    full_message: &str,
+    first_line: &str,
+    categorizer: Option<&LogCategorizer>,
+) -> LogEvent {
+    let timestamp = if first_line.len() >= 19 {
+        first_line.chars().take(19).collect()
+    } else {
+        String::new()
+    };
 
-        // Extract additional info based on category
-        let mut player_name = None;
-        let mut character_class = None;
-        let mut level = None;
-        let mut chat_sender = None;
-        let mut chat_channel = None;
+    let matched =
+        categorizer.and_then(|categorizer| categorizer.categorize(full_message, first_line));
+    let fallback = matched.is_none();
+    let category = matched.unwrap_or(Category::FALLBACK);
+    let locale = categorizer.map_or(&locale::ENGLISH, |categorizer| categorizer.locale());
 
-        match category.as_str() {
-            "Death" => {
-                player_name = extract_death_info(&full_message);
+    // Extract additional info based on category
+    let mut player_name = None;
+    let mut character_class = None;
+    let mut level = None;
+    let mut chat_sender = None;
+    let mut chat_channel = None;
+    let mut chat_text = None;
+    let mut chat_counterpart = None;
+    let mut direction = None;
+    let mut trade_request = None;
+    let mut passive_change = None;
+    let mut reward = None;
+    let mut item_filter = None;
+    let mut latency = None;
+
+    match category {
+        Category::Death => {
+            player_name = extract_death_info(full_message, locale);
+        }
+        Category::LevelUp => {
+            if let Some((name, class, lvl)) = extract_level_up_info(full_message, locale) {
+                player_name = Some(name);
+                character_class = Some(class);
+                level = Some(lvl);
             }
-            "Level Up" => {
-                if let Some((name, class, lvl)) = extract_level_up_info(&full_message) {
-                    player_name = Some(name);
-                    character_class = Some(class);
-                    level = Some(lvl);
-                }
+        }
+        Category::Skill => {
+            passive_change = parse_passive_change(full_message, locale, &timestamp);
+            if passive_change.is_none() {
+                reward = parse_reward(full_message, locale, &timestamp);
             }
-            "Trade" | "Guild" => {
-                if let Some((sender, channel)) = extract_chat_info(&full_message) {
-                    chat_sender = sender;
-                    chat_channel = Some(channel);
+        }
+        Category::Network => {
+            latency = parse_latency(full_message, &timestamp);
+        }
+        Category::ItemFilter => {
+            item_filter = parse_item_filter_event(full_message, &timestamp);
+        }
+        Category::Trade | Category::Guild => {
+            if let Some(chat) = extract_chat_info(full_message, locale) {
+                chat_sender = chat.sender;
+                chat_channel = Some(chat.channel);
+                chat_text = chat.text;
+                chat_counterpart = chat.counterpart;
+                direction = chat.direction.map(str::to_string);
+                if chat.direction.is_some() {
+                    trade_request = chat_text.as_deref().and_then(parse_trade_request);
                 }
             }
-            _ => {}
-        }
-
-        Some(LogEvent {
-            timestamp,
-            category,
-            message: full_message.clone(),
-            raw: full_message,
-            player_name,
-            character_class,
-            level,
-            chat_sender,
-            chat_channel,
-        })
+        }
+        _ => {}
+    }
+
+    LogEvent {
+        timestamp,
+        category,
+        message: full_message.to_string(),
+        raw: full_message.to_string(),
+        subsystem: extract_subsystem(full_message).map(str::to_string),
+        player_name,
+        character_class,
+        level,
+        chat_sender,
+        chat_channel,
+        chat_text,
+        chat_counterpart,
+        direction,
+        command_result: None,
+        status_change: None,
+        auto_reply: false,
+        presence: None,
+        instance_server: None,
+        latency,
+        item_filter,
+        reward,
+        passive_change,
+        character: None,
+        trade_request,
+        template_id: None,
+        template_params: Vec::new(),
+        zone: None,
+        zone_change: None,
+        muted: false,
+        fallback,
+        trade_updates: Vec::new(),
+        character_updates: Vec::new(),
+        ascension: None,
     }
 }
 
@@ -445,7 +1198,103 @@ fn main() {
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
         .manage(SafeAppState::default())
-        .invoke_handler(tauri::generate_handler![start_watching, stop_watching, open_url])
+        .setup(|app| {
+            let data_dir = app.path().app_data_dir()?;
+            let state = app.state::<SafeAppState>();
+            let mut app_state = state
+                .lock()
+                .map_err(|e| format!("Failed to lock state: {}", e))?;
+            app_state.mute_list = persistence::load_json(&data_dir, MUTE_LIST_FILE);
+            app_state.roster = persistence::load_json(&data_dir, CHARACTER_ROSTER_FILE);
+            app_state.data_dir = Some(data_dir);
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            start_watching,
+            stop_watching,
+            open_url,
+            explain_categorization,
+            list_categories,
+            coverage_report,
+            list_templates,
+            get_mute_list,
+            mute_sender,
+            unmute_sender,
+            add_mute_pattern,
+            remove_mute_pattern,
+            set_mute_action,
+            get_current_zone,
+            list_trades,
+            get_passive_history,
+            get_passive_tree,
+            get_item_filter_history,
+            latency_stats,
+            latency_samples,
+            list_instances,
+            instance_servers,
+            get_system_profile,
+            get_roster,
+            presence_history,
+            get_player_status,
+            get_character_stats,
+            list_rewards,
+            list_characters,
+            list_other_characters,
+            set_own_character,
+            list_ascendancies
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chat(line: &str) -> (Option<String>, String) {
+        let info = extract_chat_info(line, &locale::ENGLISH).expect("chat line");
+        (info.sender, info.channel)
+    }
+
+    #[test]
+    fn extracts_sender_for_every_chat_prefix() {
+        // Prefixes documented in research.md, plus party chat
+        let header = "2025/11/04 19:20:50 187967859 3ef232c2 [INFO Client 7776] ";
+        let cases = [
+            ("$The_Abyss_Guy: BUYING RITUAL PRECUSOR TABLET", Some("The_Abyss_Guy"), "global"),
+            ("#Julekuk: you were wrong", Some("Julekuk"), "local"),
+            ("%BrickToast: portal is up", Some("BrickToast"), "party"),
+            ("&BrickToast: anyone on that can help", Some("BrickToast"), "guild"),
+            ("&: GUILD UPDATE: Final FTK is done for the season", None, "guild_system"),
+            ("@From Kekius_Maxymus: hi", Some("Kekius_Maxymus"), "whisper"),
+            ("@To Kekius_Maxymus: hi", None, "whisper_outgoing"),
+        ];
+
+        for (body, sender, channel) in cases {
+            let line = format!("{}{}", header, body);
+            assert_eq!(chat(&line), (sender.map(str::to_string), channel.to_string()), "{}", body);
+        }
+    }
+
+    #[test]
+    fn chat_text_excludes_header_and_sender() {
+        let line = "2025/11/04 19:20:50 187967859 3ef232c2 [INFO Client 7776] $Seller: WTS [Item] 5 div: pm me";
+        let info = extract_chat_info(line, &locale::ENGLISH).expect("chat line");
+        assert_eq!(info.sender.as_deref(), Some("Seller"));
+        assert_eq!(info.text.as_deref(), Some("WTS [Item] 5 div: pm me"));
+    }
+
+    #[test]
+    fn whisper_counterpart_drops_guild_tag() {
+        let line = "2025/11/04 19:20:50 187967859 3ef232c2 [INFO Client 7776] @To <FTK> BrickToast: ty";
+        let info = extract_chat_info(line, &locale::ENGLISH).expect("whisper");
+        assert_eq!(info.counterpart.as_deref(), Some("BrickToast"));
+        assert_eq!(info.direction, Some("outgoing"));
+    }
+
+    #[test]
+    fn whisper_markers_inside_chat_text_are_not_whispers() {
+        let line = "2025/11/04 19:20:50 187967859 3ef232c2 [INFO Client 7776] $Foo: hey @To you";
+        assert_eq!(chat(line), (Some("Foo".to_string()), "global".to_string()));
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+pub const MUTE_LIST_FILE: &str = "mute_list.json";
+
+/// What happens to chat from a muted sender
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MuteAction {
+    /// The event is never emitted
+    #[default]
+    Drop,
+    /// The event is emitted with `muted: true` so the UI can hide or dim it
+    Flag,
+}
+
+/// Senders whose chat is suppressed, persisted in the app data directory
+/// `names` match exactly (ignoring case); `patterns` are wildcards where `*`
+/// matches any run of characters and `?` a single one, e.g. "*Currency*"
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MuteList {
+    #[serde(default)]
+    pub names: Vec<String>,
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    #[serde(default)]
+    pub action: MuteAction,
+}
+
+impl MuteList {
+    pub fn is_muted(&self, sender: &str) -> bool {
+        self.names.iter().any(|name| name.eq_ignore_ascii_case(sender))
+            || self
+                .patterns
+                .iter()
+                .any(|pattern| wildcard_match(&pattern.to_lowercase(), &sender.to_lowercase()))
+    }
+
+    pub fn add_name(&mut self, name: &str) -> bool {
+        add_unique(&mut self.names, name)
+    }
+
+    pub fn remove_name(&mut self, name: &str) -> bool {
+        remove_entry(&mut self.names, name)
+    }
+
+    pub fn add_pattern(&mut self, pattern: &str) -> bool {
+        add_unique(&mut self.patterns, pattern)
+    }
+
+    pub fn remove_pattern(&mut self, pattern: &str) -> bool {
+        remove_entry(&mut self.patterns, pattern)
+    }
+}
+
+fn add_unique(entries: &mut Vec<String>, entry: &str) -> bool {
+    let entry = entry.trim();
+    if entry.is_empty() || entries.iter().any(|e| e.eq_ignore_ascii_case(entry)) {
+        return false;
+    }
+    entries.push(entry.to_string());
+    true
+}
+
+fn remove_entry(entries: &mut Vec<String>, entry: &str) -> bool {
+    let before = entries.len();
+    entries.retain(|e| !e.eq_ignore_ascii_case(entry.trim()));
+    entries.len() != before
+}
+
+/// Glob-style match supporting `*` and `?`
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Let the last '*' swallow one more character and retry
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
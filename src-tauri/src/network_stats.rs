@@ -0,0 +1,224 @@
+use crate::log_categorizer::strip_header;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Server key for instance connect times, which don't name a host
+pub const INSTANCE_SERVER: &str = "instance server";
+
+/// One measured connection time
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencySample {
+    pub host: String,
+    // Datacenter code of login servers, e.g. "sjc" for sjc.login.pathofexile2.com
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    pub latency_ms: u32,
+    pub timestamp: String,
+}
+
+/// Parses connection lines that carry a latency
+/// Patterns:
+///   "Connected to sjc.login.pathofexile2.com in 16ms"
+///   "Connect time to instance server was 42ms"
+pub fn parse_latency(full_message: &str, timestamp: &str) -> Option<LatencySample> {
+    let body = strip_header(full_message.lines().next()?);
+
+    let (host, latency) = if let Some(rest) = body.strip_prefix("Connected to ") {
+        rest.rsplit_once(" in ")?
+    } else {
+        let latency = body.strip_prefix("Connect time to instance server was ")?;
+        (INSTANCE_SERVER, latency)
+    };
+    let latency_ms = latency.trim().trim_end_matches('.').strip_suffix("ms")?.trim().parse().ok()?;
+
+    let host = host.trim();
+    let region = host
+        .split_once(".login.")
+        .map(|(region, _)| region.to_string())
+        .filter(|region| !region.is_empty() && !region.contains('.'));
+
+    Some(LatencySample {
+        host: host.to_string(),
+        region,
+        latency_ms,
+        timestamp: timestamp.to_string(),
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencySummary {
+    pub samples: usize,
+    pub min_ms: u32,
+    pub median_ms: u32,
+    pub p95_ms: u32,
+    pub max_ms: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerLatency {
+    pub host: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    pub summary: LatencySummary,
+}
+
+/// Latency of one game session, i.e. one client start
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionLatency {
+    pub session: usize,
+    pub started_at: String,
+    pub summary: LatencySummary,
+    pub servers: Vec<ServerLatency>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencyReport {
+    pub servers: Vec<ServerLatency>,
+    pub sessions: Vec<SessionLatency>,
+}
+
+struct Session {
+    started_at: String,
+    samples: Vec<LatencySample>,
+}
+
+/// Keeps every latency sample, grouped by client session
+#[derive(Default)]
+pub struct LatencyTracker {
+    sessions: Vec<Session>,
+}
+
+impl LatencyTracker {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn start_session(&mut self, timestamp: &str) {
+        self.sessions.push(Session {
+            started_at: timestamp.to_string(),
+            samples: Vec::new(),
+        });
+    }
+
+    pub fn record(&mut self, sample: LatencySample) {
+        if self.sessions.is_empty() {
+            self.start_session(&sample.timestamp);
+        }
+        if let Some(session) = self.sessions.last_mut() {
+            session.samples.push(sample);
+        }
+    }
+
+    /// Samples in log order, optionally limited to one host
+    pub fn samples(&self, host: Option<&str>) -> Vec<LatencySample> {
+        self.sessions
+            .iter()
+            .flat_map(|session| &session.samples)
+            .filter(|sample| host.is_none() || host == Some(sample.host.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    pub fn report(&self) -> LatencyReport {
+        let all: Vec<&LatencySample> = self.sessions.iter().flat_map(|session| &session.samples).collect();
+
+        let sessions = self
+            .sessions
+            .iter()
+            .enumerate()
+            .filter_map(|(index, session)| {
+                let samples: Vec<&LatencySample> = session.samples.iter().collect();
+                Some(SessionLatency {
+                    session: index + 1,
+                    started_at: session.started_at.clone(),
+                    summary: summarize(&samples)?,
+                    servers: per_server(&samples),
+                })
+            })
+            .collect();
+
+        LatencyReport {
+            servers: per_server(&all),
+            sessions,
+        }
+    }
+}
+
+fn per_server(samples: &[&LatencySample]) -> Vec<ServerLatency> {
+    let mut by_host: HashMap<&str, Vec<&LatencySample>> = HashMap::new();
+    for sample in samples {
+        by_host.entry(sample.host.as_str()).or_default().push(sample);
+    }
+
+    let mut servers: Vec<ServerLatency> = by_host
+        .into_iter()
+        .filter_map(|(host, samples)| {
+            Some(ServerLatency {
+                host: host.to_string(),
+                region: samples[0].region.clone(),
+                summary: summarize(&samples)?,
+            })
+        })
+        .collect();
+    servers.sort_by(|a, b| a.host.cmp(&b.host));
+    servers
+}
+
+/// Nearest-rank percentiles over the samples; None when there are none
+fn summarize(samples: &[&LatencySample]) -> Option<LatencySummary> {
+    if samples.is_empty() {
+        return None;
+    }
+    let mut values: Vec<u32> = samples.iter().map(|sample| sample.latency_ms).collect();
+    values.sort_unstable();
+    // Index of the ceil(n * p / 100)th value
+    let percentile = |p: usize| values[(values.len() * p - 1) / 100];
+
+    Some(LatencySummary {
+        samples: values.len(),
+        min_ms: values[0],
+        median_ms: percentile(50),
+        p95_ms: percentile(95),
+        max_ms: values[values.len() - 1],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(latencies: &[u32]) -> Vec<LatencySample> {
+        latencies
+            .iter()
+            .map(|&latency_ms| LatencySample {
+                host: "sjc.login.pathofexile2.com".to_string(),
+                region: Some("sjc".to_string()),
+                latency_ms,
+                timestamp: "2025/11/04 19:00:00".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn single_sample_is_every_percentile() {
+        let samples = samples(&[42]);
+        let summary = summarize(&samples.iter().collect::<Vec<_>>()).expect("summary");
+        assert_eq!((summary.min_ms, summary.median_ms, summary.p95_ms, summary.max_ms), (42, 42, 42, 42));
+    }
+
+    #[test]
+    fn nearest_rank_percentiles_of_twenty_samples() {
+        // Shuffled 1..=20, so the summary has to sort
+        let samples = samples(&[7, 20, 1, 14, 3, 18, 10, 5, 12, 19, 2, 16, 9, 11, 4, 17, 6, 13, 8, 15]);
+        let summary = summarize(&samples.iter().collect::<Vec<_>>()).expect("summary");
+        assert_eq!(summary.samples, 20);
+        // ceil(20 * 0.50) = 10th and ceil(20 * 0.95) = 19th smallest
+        assert_eq!((summary.median_ms, summary.p95_ms), (10, 19));
+        assert_eq!((summary.min_ms, summary.max_ms), (1, 20));
+    }
+
+    #[test]
+    fn no_samples_no_summary() {
+        assert!(summarize(&[]).is_none());
+    }
+}
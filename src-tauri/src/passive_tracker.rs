@@ -0,0 +1,133 @@
+use crate::locale::{self, LocalePack};
+use crate::log_categorizer::strip_header;
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PassiveAction {
+    Allocated,
+    Unallocated,
+    Reset,
+}
+
+/// One step in a character's passive tree history
+#[derive(Debug, Clone, Serialize)]
+pub struct PassiveChange {
+    pub action: PassiveAction,
+    // Internal node id, e.g. "projectiles18"; absent for a full reset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passive_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passive_name: Option<String>,
+    pub timestamp: String,
+}
+
+/// Allocated nodes of a character's tree at a point in time
+#[derive(Debug, Clone, Serialize)]
+pub struct PassiveTree {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub character: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at: Option<String>,
+    pub nodes: Vec<String>,
+    // Single-node refunds and full resets up to `at`
+    pub deallocations: usize,
+    pub respecs: usize,
+}
+
+/// Parses allocation, de-allocation and reset lines
+/// Pattern: ": Successfully allocated passive skill id: projectiles18, name: Projectile Damage"
+pub fn parse_passive_change(full_message: &str, locale: &LocalePack, timestamp: &str) -> Option<PassiveChange> {
+    let body = strip_header(full_message.lines().next()?);
+    let content = body.strip_prefix(": ").unwrap_or(body);
+
+    if content.starts_with(locale.passives_reset) {
+        return Some(PassiveChange {
+            action: PassiveAction::Reset,
+            passive_id: None,
+            passive_name: None,
+            timestamp: timestamp.to_string(),
+        });
+    }
+
+    let (action, values) = if let Some(values) = locale::match_template(locale.passive_allocated, content) {
+        (PassiveAction::Allocated, values)
+    } else {
+        (PassiveAction::Unallocated, locale::match_template(locale.passive_unallocated, content)?)
+    };
+
+    // The id placeholder runs to the end of the line and may carry ", name: ..."
+    let rest = locale::field(&values, "id")?;
+    let (id, name) = match rest.split_once(',') {
+        Some((id, tail)) => {
+            let name = tail.trim().split_once(':').map(|(_, name)| name.trim().to_string());
+            (id, name)
+        }
+        None => (rest, None),
+    };
+    let id = id.trim();
+    if id.is_empty() {
+        return None;
+    }
+
+    Some(PassiveChange {
+        action,
+        passive_id: Some(id.to_string()),
+        passive_name: name.filter(|name| !name.is_empty()),
+        timestamp: timestamp.to_string(),
+    })
+}
+
+/// Per-character ordered history of passive tree changes
+/// Changes made before any character was identified are kept under None
+#[derive(Default)]
+pub struct PassiveTracker {
+    history: HashMap<Option<String>, Vec<PassiveChange>>,
+}
+
+impl PassiveTracker {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn record(&mut self, character: Option<&str>, change: PassiveChange) {
+        self.history.entry(character.map(str::to_string)).or_default().push(change);
+    }
+
+    pub fn history(&self, character: Option<&str>) -> Vec<PassiveChange> {
+        self.history.get(&character.map(str::to_string)).cloned().unwrap_or_default()
+    }
+
+    /// Replays the history up to and including `at` ("YYYY/MM/DD HH:MM:SS")
+    pub fn tree_at(&self, character: Option<&str>, at: Option<&str>) -> PassiveTree {
+        let character = character.map(str::to_string);
+        let mut tree = PassiveTree {
+            character: character.clone(),
+            at: at.map(str::to_string),
+            nodes: Vec::new(),
+            deallocations: 0,
+            respecs: 0,
+        };
+
+        // Log timestamps sort lexicographically, so a string compare is enough
+        let changes = self.history.get(&character).into_iter().flatten();
+        for change in changes.take_while(|change| at.filter(|at| change.timestamp.as_str() > *at).is_none()) {
+            match (change.action, change.passive_id.as_ref()) {
+                (PassiveAction::Allocated, Some(id)) if !tree.nodes.contains(id) => {
+                    tree.nodes.push(id.clone());
+                }
+                (PassiveAction::Unallocated, Some(id)) => {
+                    tree.nodes.retain(|node| node != id);
+                    tree.deallocations += 1;
+                }
+                (PassiveAction::Reset, _) => {
+                    tree.nodes.clear();
+                    tree.respecs += 1;
+                }
+                _ => {}
+            }
+        }
+        tree
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::Path;
+
+/// Loads a JSON file from the app data directory, falling back to the default
+/// when it doesn't exist yet or can't be parsed
+pub fn load_json<T: DeserializeOwned + Default>(data_dir: &Path, file_name: &str) -> T {
+    let path = data_dir.join(file_name);
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+            eprintln!("Failed to parse {}: {}", path.display(), e);
+            T::default()
+        }),
+        Err(_) => T::default(),
+    }
+}
+
+pub fn save_json<T: Serialize>(data_dir: &Path, file_name: &str, value: &T) -> Result<(), String> {
+    std::fs::create_dir_all(data_dir)
+        .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    let contents = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize {}: {}", file_name, e))?;
+    std::fs::write(data_dir.join(file_name), contents)
+        .map_err(|e| format!("Failed to write {}: {}", file_name, e))
+}
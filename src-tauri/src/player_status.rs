@@ -0,0 +1,158 @@
+use crate::locale::{self, LocalePack};
+use crate::log_categorizer::strip_header;
+use crate::timestamp::parse_timestamp;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusMode {
+    Afk,
+    Dnd,
+}
+
+/// AFK or DND mode switched on or off
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusChange {
+    pub mode: StatusMode,
+    pub enabled: bool,
+    // Message the client sends back to whispers while the mode is on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_reply: Option<String>,
+    pub timestamp: String,
+}
+
+/// Parses status lines
+/// Patterns:
+///   ": AFK mode is now ON. Autoreply "This player is AFK."" (or the locale's equivalent)
+///   ": AFK mode is now OFF."
+pub fn parse_status_change(full_message: &str, locale: &LocalePack, timestamp: &str) -> Option<StatusChange> {
+    let content = strip_header(full_message.lines().next()?).strip_prefix(": ")?;
+
+    let (enabled, values) = if let Some(values) = locale::match_template(locale.status_on, content) {
+        (true, values)
+    } else {
+        (false, locale::match_template(locale.status_off, content)?)
+    };
+
+    let mode = match locale::field(&values, "mode")?.trim() {
+        "AFK" => StatusMode::Afk,
+        "DND" => StatusMode::Dnd,
+        _ => return None,
+    };
+
+    Some(StatusChange {
+        mode,
+        enabled,
+        auto_reply: locale::field(&values, "reply")
+            .map(str::to_string)
+            .filter(|reply| !reply.is_empty()),
+        timestamp: timestamp.to_string(),
+    })
+}
+
+/// Current AFK/DND state returned by `get_player_status`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PlayerStatus {
+    pub afk: bool,
+    pub dnd: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub afk_since: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_reply: Option<String>,
+    // Completed AFK periods, for excluding idle time from playtime
+    pub total_afk_seconds: i64,
+}
+
+#[derive(Default)]
+pub struct StatusTracker {
+    status: PlayerStatus,
+}
+
+impl StatusTracker {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// A new client process starts with AFK and DND off, so an open AFK period is dropped
+    /// rather than counted across the restart
+    pub fn start_session(&mut self) {
+        let total_afk_seconds = self.status.total_afk_seconds;
+        self.status = PlayerStatus {
+            total_afk_seconds,
+            ..PlayerStatus::default()
+        };
+    }
+
+    pub fn record(&mut self, change: &StatusChange) {
+        let status = &mut self.status;
+        match (change.mode, change.enabled) {
+            (StatusMode::Afk, true) => {
+                if !status.afk {
+                    status.afk_since = Some(change.timestamp.clone());
+                }
+                status.afk = true;
+            }
+            (StatusMode::Afk, false) => {
+                let since = status.afk_since.take();
+                if let (Some(start), Some(end)) = (
+                    since.as_deref().and_then(parse_timestamp),
+                    parse_timestamp(&change.timestamp),
+                ) {
+                    status.total_afk_seconds += (end - start).max(0);
+                }
+                status.afk = false;
+            }
+            (StatusMode::Dnd, enabled) => status.dnd = enabled,
+        }
+
+        if change.enabled {
+            status.auto_reply = change.auto_reply.clone();
+        } else if !status.afk && !status.dnd {
+            status.auto_reply = None;
+        }
+    }
+
+    /// True for an outgoing whisper the client sent on its own while AFK/DND
+    pub fn is_auto_reply(&self, text: &str) -> bool {
+        self.status.auto_reply.as_deref() == Some(text)
+    }
+
+    pub fn status(&self) -> PlayerStatus {
+        self.status.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn afk(enabled: bool, timestamp: &str) -> StatusChange {
+        StatusChange {
+            mode: StatusMode::Afk,
+            enabled,
+            auto_reply: None,
+            timestamp: timestamp.to_string(),
+        }
+    }
+
+    #[test]
+    fn afk_period_does_not_survive_a_client_restart() {
+        let mut tracker = StatusTracker::default();
+        tracker.record(&afk(true, "2025/11/04 10:00:00"));
+        tracker.start_session();
+        assert!(!tracker.status().afk);
+
+        tracker.record(&afk(true, "2025/11/06 10:00:00"));
+        tracker.record(&afk(false, "2025/11/06 10:05:00"));
+        assert_eq!(tracker.status().total_afk_seconds, 300);
+    }
+
+    #[test]
+    fn restart_keeps_completed_afk_time() {
+        let mut tracker = StatusTracker::default();
+        tracker.record(&afk(true, "2025/11/04 10:00:00"));
+        tracker.record(&afk(false, "2025/11/04 10:01:00"));
+        tracker.start_session();
+        assert_eq!(tracker.status().total_afk_seconds, 60);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::locale::{self, LocalePack};
+use crate::log_categorizer::strip_header;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PresenceAction {
+    Joined,
+    Left,
+}
+
+/// Another player entering or leaving our instance
+#[derive(Debug, Clone, Serialize)]
+pub struct PresenceEvent {
+    pub name: String,
+    pub action: PresenceAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+    pub timestamp: String,
+}
+
+/// Parses join/leave lines
+/// Pattern: ": PlayerName has joined the area." (or the locale's equivalent)
+pub fn parse_presence<'a>(full_message: &'a str, locale: &LocalePack) -> Option<(PresenceAction, &'a str)> {
+    let content = strip_header(full_message.lines().next()?).strip_prefix(": ")?;
+
+    let (action, values) = if let Some(values) = locale::match_template(locale.area_joined, content) {
+        (PresenceAction::Joined, values)
+    } else {
+        (PresenceAction::Left, locale::match_template(locale.area_left, content)?)
+    };
+
+    let name = locale::field(&values, "name")?.trim();
+    if name.is_empty() || name.contains(' ') {
+        return None;
+    }
+    Some((action, name))
+}
+
+/// Who is in our instance right now, plus every join/leave seen
+#[derive(Default)]
+pub struct PresenceTracker {
+    roster: Vec<String>,
+    history: Vec<PresenceEvent>,
+}
+
+impl PresenceTracker {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// A new area is a new instance, so nobody from the old one is with us
+    pub fn zone_changed(&mut self) {
+        self.roster.clear();
+    }
+
+    pub fn record(&mut self, event: PresenceEvent) {
+        match event.action {
+            PresenceAction::Joined => {
+                if !self.roster.contains(&event.name) {
+                    self.roster.push(event.name.clone());
+                }
+            }
+            PresenceAction::Left => self.roster.retain(|name| name != &event.name),
+        }
+        self.history.push(event);
+    }
+
+    pub fn roster(&self) -> Vec<String> {
+        self.roster.clone()
+    }
+
+    /// Joins and leaves in log order, optionally limited to one zone
+    pub fn history(&self, zone: Option<&str>) -> Vec<PresenceEvent> {
+        self.history
+            .iter()
+            .filter(|event| zone.is_none() || event.zone.as_deref() == zone)
+            .cloned()
+            .collect()
+    }
+}
@@ -0,0 +1,97 @@
+use crate::locale::{self, LocalePack};
+use crate::log_categorizer::strip_header;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Punctuation closing a reward line in any client language
+const SENTENCE_END: [char; 3] = ['.', '!', '。'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RewardKind {
+    PassivePoints,
+    SkillGem,
+    StatBonus,
+    Item,
+}
+
+/// Something the character received, e.g. a quest reward
+#[derive(Debug, Clone, Serialize)]
+pub struct Reward {
+    pub kind: RewardKind,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+    pub timestamp: String,
+}
+
+/// Parses "received" lines into a reward
+/// Patterns:
+///   ": You have received 2 Passive Skill Points." -> PassivePoints, amount 2
+///   ": You have received +10% to Fire Resistance." -> StatBonus
+///   ": You have received Uncut Skill Gem." -> SkillGem
+///   ": You have received Book of Specialisation." -> Item
+pub fn parse_reward(full_message: &str, locale: &LocalePack, timestamp: &str) -> Option<Reward> {
+    let body = strip_header(full_message.lines().next()?);
+    let content = body.strip_prefix(": ").unwrap_or(body);
+    let values = locale::match_template(locale.item_received, content)?;
+
+    let text = locale::field(&values, "reward")?.trim_end_matches(SENTENCE_END).trim();
+    let text = text
+        .strip_prefix("a ")
+        .or_else(|| text.strip_prefix("an "))
+        .unwrap_or(text);
+
+    let (amount, name) = match text.split_once(' ') {
+        Some((number, rest)) if number.chars().all(|c| c.is_ascii_digit()) => {
+            (number.parse().ok(), rest.trim())
+        }
+        _ => (None, text),
+    };
+    if name.is_empty() {
+        return None;
+    }
+
+    let lower = name.to_lowercase();
+    let kind = if lower.contains("passive skill point") {
+        RewardKind::PassivePoints
+    } else if lower.contains("skill gem") {
+        RewardKind::SkillGem
+    } else if name.starts_with('+') || name.starts_with('-') || name.contains('%') {
+        RewardKind::StatBonus
+    } else {
+        RewardKind::Item
+    };
+
+    Some(Reward {
+        kind,
+        name: name.to_string(),
+        amount,
+        zone: None,
+        timestamp: timestamp.to_string(),
+    })
+}
+
+/// Per-character log of rewards and the zone they were received in
+/// Rewards received before any character was identified are kept under None
+#[derive(Default)]
+pub struct RewardLog {
+    rewards: HashMap<Option<String>, Vec<Reward>>,
+}
+
+impl RewardLog {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn record(&mut self, character: Option<&str>, reward: Reward) {
+        self.rewards.entry(character.map(str::to_string)).or_default().push(reward);
+    }
+
+    /// Rewards of one character in log order
+    pub fn rewards(&self, character: Option<&str>) -> Vec<Reward> {
+        self.rewards.get(&character.map(str::to_string)).cloned().unwrap_or_default()
+    }
+}
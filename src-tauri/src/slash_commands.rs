@@ -0,0 +1,159 @@
+use crate::locale::{self, LocalePack};
+use crate::log_categorizer::strip_header;
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlashCommand {
+    Played,
+    Age,
+    Deaths,
+    Passives,
+}
+
+/// Parsed output of an in-game slash command
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandResult {
+    pub command: SlashCommand,
+    // Duration for /played and /age
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seconds: Option<u64>,
+    // Death count for /deaths, point count for /passives
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    // Which passive point total a /passives line reports, e.g. "quests"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    pub timestamp: String,
+}
+
+/// Most recent slash command values of one character
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CharacterStats {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub character: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub played_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deaths: Option<u32>,
+    // Passive point totals by source, e.g. {"total": 24, "quests": 22}
+    pub passive_points: HashMap<String, u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Parses the output of /played, /age, /deaths and /passives
+/// Patterns (or the locale's equivalent):
+///   ": You have played for 2 days, 3 hours, 4 minutes and 5 seconds."
+///   ": Your character was created 5 days, 1 hour and 2 minutes ago."
+///   ": You have died 12 times." / ": You have never died."
+///   ": You have 22 Passive Skill Points from quests." / ": You have 24 Passive Skill Points."
+/// Only system lines count, so chat quoting the same wording is ignored. Duration
+/// units and the "from" of /passives are still read as English.
+pub fn parse_command_result(full_message: &str, locale: &LocalePack, timestamp: &str) -> Option<CommandResult> {
+    let content = strip_header(full_message.lines().next()?)
+        .strip_prefix(": ")?
+        .trim_end_matches('.');
+
+    let mut result = CommandResult {
+        command: SlashCommand::Played,
+        seconds: None,
+        count: None,
+        source: None,
+        timestamp: timestamp.to_string(),
+    };
+
+    if let Some(values) = locale::match_template(locale.played, content) {
+        result.seconds = Some(parse_duration(locale::field(&values, "duration")?)?);
+    } else if let Some(values) = locale::match_template(locale.character_age, content) {
+        result.command = SlashCommand::Age;
+        result.seconds = Some(parse_duration(locale::field(&values, "duration")?)?);
+    } else if content == locale.never_died {
+        result.command = SlashCommand::Deaths;
+        result.count = Some(0);
+    } else if let Some(values) = locale::match_template(locale.died, content) {
+        result.command = SlashCommand::Deaths;
+        result.count = Some(locale::field(&values, "count")?.trim().parse().ok()?);
+    } else if let Some(values) = locale::match_template(locale.passive_points, content) {
+        // "You have received ..." reward lines fail the count parse
+        let count = locale::field(&values, "count")?.trim().parse().ok()?;
+        let rest = locale::field(&values, "source")?;
+        let rest = rest.strip_prefix('s').unwrap_or(rest).trim();
+        let source = if rest.is_empty() {
+            "total"
+        } else {
+            rest.strip_prefix("from ")?.trim()
+        };
+        result.command = SlashCommand::Passives;
+        result.count = Some(count);
+        result.source = Some(source.to_lowercase());
+    } else {
+        return None;
+    }
+    Some(result)
+}
+
+/// "2 days, 3 hours, 4 minutes and 5 seconds" -> 183845
+fn parse_duration(text: &str) -> Option<u64> {
+    let tokens: Vec<&str> = text
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty() && *token != "and")
+        .collect();
+
+    let mut seconds = 0;
+    let mut found = false;
+    for pair in tokens.chunks(2) {
+        let [value, unit] = pair else { return None };
+        let value: u64 = value.parse().ok()?;
+        let unit_seconds = match unit.trim_end_matches('s') {
+            "day" => 86_400,
+            "hour" => 3_600,
+            "minute" => 60,
+            "second" => 1,
+            _ => return None,
+        };
+        seconds += value * unit_seconds;
+        found = true;
+    }
+    found.then_some(seconds)
+}
+
+/// Latest slash command values per character
+/// Results printed before any character was identified are kept under None
+#[derive(Default)]
+pub struct CharacterStatsTracker {
+    stats: HashMap<Option<String>, CharacterStats>,
+}
+
+impl CharacterStatsTracker {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn record(&mut self, character: Option<&str>, result: &CommandResult) {
+        let character = character.map(str::to_string);
+        let stats = self.stats.entry(character.clone()).or_insert_with(|| CharacterStats {
+            character,
+            ..CharacterStats::default()
+        });
+
+        match result.command {
+            SlashCommand::Played => stats.played_seconds = result.seconds,
+            SlashCommand::Age => stats.age_seconds = result.seconds,
+            SlashCommand::Deaths => stats.deaths = result.count,
+            SlashCommand::Passives => {
+                if let (Some(source), Some(count)) = (&result.source, result.count) {
+                    stats.passive_points.insert(source.clone(), count);
+                }
+            }
+        }
+        stats.updated_at = Some(result.timestamp.clone());
+    }
+
+    pub fn stats(&self, character: Option<&str>) -> Option<CharacterStats> {
+        self.stats.get(&character.map(str::to_string)).cloned()
+    }
+}
@@ -0,0 +1,168 @@
+use crate::log_categorizer::strip_header;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileField {
+    Gpu,
+    DriverVersion,
+    OsVersion,
+    RenderBackend,
+}
+
+/// Startup line prefixes and the profile field their value fills
+pub const PROFILE_KEYS: &[(&str, ProfileField)] = &[
+    ("GPU:", ProfileField::Gpu),
+    ("Adapter:", ProfileField::Gpu),
+    ("Physical Device:", ProfileField::Gpu),
+    ("Device Name:", ProfileField::Gpu),
+    ("Driver Version:", ProfileField::DriverVersion),
+    ("Windows Version:", ProfileField::OsVersion),
+    ("OS:", ProfileField::OsVersion),
+];
+
+/// Subsystem tags of the renderer lines and the backend they reveal
+pub const RENDER_BACKEND_TAGS: &[(&str, &str)] = &[("VULKAN", "Vulkan"), ("DX12", "DX12")];
+
+/// A field that differs from the previous session, e.g. after a driver update
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileChange {
+    pub field: ProfileField,
+    pub previous: String,
+    pub current: String,
+}
+
+/// Hardware and client setup reported at one client start
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemProfile {
+    pub session: usize,
+    pub started_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpu: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub render_backend: Option<String>,
+    pub changes: Vec<ProfileChange>,
+}
+
+impl SystemProfile {
+    fn new(session: usize, started_at: &str) -> Self {
+        Self {
+            session,
+            started_at: started_at.to_string(),
+            gpu: None,
+            driver_version: None,
+            os_version: None,
+            render_backend: None,
+            changes: Vec::new(),
+        }
+    }
+
+    fn field(&self, field: ProfileField) -> &Option<String> {
+        match field {
+            ProfileField::Gpu => &self.gpu,
+            ProfileField::DriverVersion => &self.driver_version,
+            ProfileField::OsVersion => &self.os_version,
+            ProfileField::RenderBackend => &self.render_backend,
+        }
+    }
+
+    fn field_mut(&mut self, field: ProfileField) -> &mut Option<String> {
+        match field {
+            ProfileField::Gpu => &mut self.gpu,
+            ProfileField::DriverVersion => &mut self.driver_version,
+            ProfileField::OsVersion => &mut self.os_version,
+            ProfileField::RenderBackend => &mut self.render_backend,
+        }
+    }
+}
+
+/// Finds the profile field and value in a startup line
+/// Patterns:
+///   "... [INFO Client 7776] [VULKAN] Driver Version: 566.36" -> (DriverVersion, "566.36")
+///   "... [INFO Client 7776] [VULKAN] <anything else>" -> (RenderBackend, "Vulkan")
+pub fn parse_profile_line(full_message: &str) -> Option<(ProfileField, &str)> {
+    let mut body = strip_header(full_message.lines().next()?);
+
+    // Skip a subsystem tag such as "[VULKAN] "
+    let mut tag = None;
+    if let Some(tagged) = body.strip_prefix('[') {
+        if let Some(end) = tagged.find("] ") {
+            tag = Some(&tagged[..end]);
+            body = &tagged[end + 2..];
+        }
+    }
+
+    let value = PROFILE_KEYS.iter().find_map(|(key, field)| {
+        let value = body.strip_prefix(key)?.trim();
+        (!value.is_empty()).then_some((*field, value))
+    });
+    value.or_else(|| {
+        let tag = tag?;
+        RENDER_BACKEND_TAGS
+            .iter()
+            .find(|(backend_tag, _)| *backend_tag == tag)
+            .map(|(_, backend)| (ProfileField::RenderBackend, *backend))
+    })
+}
+
+/// One system profile per client session
+#[derive(Default)]
+pub struct SystemProfileTracker {
+    profiles: Vec<SystemProfile>,
+}
+
+impl SystemProfileTracker {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn start_session(&mut self, timestamp: &str) {
+        let session = self.profiles.len() + 1;
+        self.profiles.push(SystemProfile::new(session, timestamp));
+    }
+
+    /// Stores the first value of a field per session and flags it if it changed
+    pub fn record(&mut self, field: ProfileField, value: &str, timestamp: &str) {
+        if self.profiles.is_empty() {
+            self.start_session(timestamp);
+        }
+
+        let previous = self
+            .profiles
+            .iter()
+            .rev()
+            .skip(1)
+            .find_map(|profile| profile.field(field).clone());
+
+        let Some(current) = self.profiles.last_mut() else {
+            return;
+        };
+        let slot = current.field_mut(field);
+        // Several adapters may be listed; keep the first one, which the client uses
+        if slot.is_some() {
+            return;
+        }
+        *slot = Some(value.to_string());
+
+        if let Some(previous) = previous.filter(|previous| previous != value) {
+            current.changes.push(ProfileChange {
+                field,
+                previous,
+                current: value.to_string(),
+            });
+        }
+    }
+
+    /// Profile of one session, or of the latest one when `session` is None
+    pub fn profile(&self, session: Option<usize>) -> Option<SystemProfile> {
+        match session {
+            Some(session) => self.profiles.iter().find(|profile| profile.session == session),
+            None => self.profiles.last(),
+        }
+        .cloned()
+    }
+}
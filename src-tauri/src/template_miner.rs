@@ -0,0 +1,194 @@
+use crate::log_categorizer::strip_header;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Placeholder for a variable token in a mined template
+const WILDCARD: &str = "*";
+
+/// Fraction of tokens that must agree for a message to join a cluster
+const SIMILARITY_THRESHOLD: f64 = 0.5;
+
+/// Leading tokens used to route a message before comparing clusters
+const PREFIX_DEPTH: usize = 2;
+
+/// Clusters kept per leaf before new messages are forced into the closest one
+const MAX_CLUSTERS_PER_LEAF: usize = 64;
+
+/// A message structure learned from the log, e.g. "Connected to * in *"
+#[derive(Debug, Clone, Serialize)]
+pub struct LogTemplate {
+    pub id: u32,
+    pub template: String,
+    pub count: usize,
+    pub first_seen: String,
+    pub last_seen: String,
+    pub sample: String,
+}
+
+struct Cluster {
+    tokens: Vec<String>,
+    info: LogTemplate,
+}
+
+/// Online Drain-style template miner
+/// Messages are routed by token count and leading tokens, then merged into the
+/// most similar cluster of that leaf; tokens that differ become wildcards.
+#[derive(Default)]
+pub struct TemplateMiner {
+    clusters: Vec<Cluster>,
+    /// (token count, leading tokens) -> indices into `clusters`
+    leaves: HashMap<(usize, Vec<String>), Vec<usize>>,
+}
+
+impl TemplateMiner {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Assigns the message to a template and returns its id and variable parameters
+    pub fn add(&mut self, full_message: &str, timestamp: &str) -> (u32, Vec<String>) {
+        let first_line = full_message.lines().next().unwrap_or_default();
+        let body = strip_header(first_line);
+        let tokens: Vec<&str> = body.split_whitespace().collect();
+
+        let key = (tokens.len(), route_prefix(&tokens));
+        let leaf = self.leaves.entry(key).or_default();
+
+        let best = leaf
+            .iter()
+            .map(|&index| (index, similarity(&self.clusters[index].tokens, &tokens)))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        let index = match best {
+            Some((index, score))
+                if score >= SIMILARITY_THRESHOLD || leaf.len() >= MAX_CLUSTERS_PER_LEAF =>
+            {
+                let cluster = &mut self.clusters[index];
+                for (template_token, token) in cluster.tokens.iter_mut().zip(&tokens) {
+                    if template_token != token {
+                        *template_token = WILDCARD.to_string();
+                    }
+                }
+                cluster.info.template = cluster.tokens.join(" ");
+                cluster.info.count += 1;
+                cluster.info.last_seen = timestamp.to_string();
+                index
+            }
+            _ => {
+                let index = self.clusters.len();
+                self.clusters.push(Cluster {
+                    tokens: tokens.iter().map(|t| t.to_string()).collect(),
+                    info: LogTemplate {
+                        id: index as u32 + 1,
+                        template: tokens.join(" "),
+                        count: 1,
+                        first_seen: timestamp.to_string(),
+                        last_seen: timestamp.to_string(),
+                        sample: body.to_string(),
+                    },
+                });
+                leaf.push(index);
+                index
+            }
+        };
+
+        let cluster = &self.clusters[index];
+        let parameters = cluster
+            .tokens
+            .iter()
+            .zip(&tokens)
+            .filter(|(template_token, _)| *template_token == WILDCARD)
+            .map(|(_, token)| token.to_string())
+            .collect();
+
+        (cluster.info.id, parameters)
+    }
+
+    /// All templates, most frequent first
+    pub fn templates(&self) -> Vec<LogTemplate> {
+        let mut templates: Vec<LogTemplate> =
+            self.clusters.iter().map(|cluster| cluster.info.clone()).collect();
+        templates.sort_by(|a, b| b.count.cmp(&a.count).then(a.id.cmp(&b.id)));
+        templates
+    }
+}
+
+/// Leading tokens used for routing; tokens with digits are treated as variable
+fn route_prefix(tokens: &[&str]) -> Vec<String> {
+    tokens
+        .iter()
+        .take(PREFIX_DEPTH)
+        .map(|token| {
+            if token.chars().any(|c| c.is_ascii_digit()) {
+                WILDCARD.to_string()
+            } else {
+                token.to_string()
+            }
+        })
+        .collect()
+}
+
+/// Share of positions where the message token fits the template token
+fn similarity(template: &[String], tokens: &[&str]) -> f64 {
+    if tokens.is_empty() {
+        return 1.0;
+    }
+    let same = template
+        .iter()
+        .zip(tokens)
+        .filter(|(template_token, token)| {
+            template_token.as_str() == WILDCARD || template_token.as_str() == **token
+        })
+        .count();
+    same as f64 / tokens.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "2025/11/04 19:20:50 187967859 3ef232c2 [INFO Client 7776] ";
+
+    fn add(miner: &mut TemplateMiner, body: &str) -> (u32, Vec<String>) {
+        miner.add(&format!("{}{}", HEADER, body), "2025/11/04 19:20:50")
+    }
+
+    #[test]
+    fn similar_messages_merge_into_one_template() {
+        let mut miner = TemplateMiner::default();
+        let (first, params) = add(&mut miner, "Connected to sjc.login.pathofexile2.com in 16ms");
+        assert!(params.is_empty());
+
+        let (second, params) = add(&mut miner, "Connected to ams.login.pathofexile2.com in 23ms");
+        assert_eq!(first, second);
+        assert_eq!(params, ["ams.login.pathofexile2.com", "23ms"]);
+
+        let templates = miner.templates();
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].template, "Connected to * in *");
+        assert_eq!(templates[0].count, 2);
+        assert_eq!(templates[0].sample, "Connected to sjc.login.pathofexile2.com in 16ms");
+    }
+
+    #[test]
+    fn wildcards_capture_the_params_of_later_messages() {
+        let mut miner = TemplateMiner::default();
+        add(&mut miner, "Connected to sjc.login.pathofexile2.com in 16ms");
+        add(&mut miner, "Connected to ams.login.pathofexile2.com in 23ms");
+
+        let (_, params) = add(&mut miner, "Connected to fra.login.pathofexile2.com in 31ms");
+        assert_eq!(params, ["fra.login.pathofexile2.com", "31ms"]);
+    }
+
+    #[test]
+    fn different_structures_stay_apart() {
+        let mut miner = TemplateMiner::default();
+        let (connected, _) = add(&mut miner, "Connected to sjc.login.pathofexile2.com in 16ms");
+        let (other, _) = add(&mut miner, "Got Instance Details from login server");
+        let (longer, _) = add(&mut miner, "Connected to sjc.login.pathofexile2.com in 16ms again");
+
+        assert_ne!(connected, other);
+        assert_ne!(connected, longer);
+        assert_eq!(miner.templates().len(), 3);
+    }
+}
@@ -0,0 +1,65 @@
+/// Converts a "YYYY/MM/DD HH:MM:SS" log timestamp to seconds since the Unix epoch
+/// The client writes local time, so only differences between timestamps are meaningful
+pub fn parse_timestamp(timestamp: &str) -> Option<i64> {
+    let (date, time) = timestamp.get(..19)?.split_once(' ')?;
+    let mut date_parts = date.split('/').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (date_parts.next()??, date_parts.next()??, date_parts.next()??);
+    let mut time_parts = time.split(':').map(|part| part.parse::<i64>().ok());
+    let (hour, minute, second) = (time_parts.next()??, time_parts.next()??, time_parts.next()??);
+
+    // Days from civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second)
+}
+
+
+/// Formats seconds from `parse_timestamp` back into a "YYYY/MM/DD HH:MM:SS" log timestamp
+pub fn format_timestamp(seconds: i64) -> String {
+    let (days, time) = (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+
+    // Civil date from days, the inverse of the conversion above
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}/{:02}/{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_is_the_inverse_of_parse() {
+        for timestamp in ["1970/01/01 00:00:00", "2024/02/29 23:59:59", "2025/11/04 19:20:50", "2100/03/01 00:00:00"] {
+            let seconds = parse_timestamp(timestamp).expect("timestamp");
+            assert_eq!(format_timestamp(seconds), timestamp);
+        }
+    }
+
+    #[test]
+    fn differences_cross_day_and_year_boundaries() {
+        let seconds = parse_timestamp("2025/12/31 23:55:00").unwrap() + 600;
+        assert_eq!(format_timestamp(seconds), "2026/01/01 00:05:00");
+    }
+}
@@ -0,0 +1,194 @@
+use crate::locale::LocalePack;
+use crate::log_categorizer::is_system_message;
+use crate::presence::{parse_presence, PresenceAction};
+use crate::timestamp::{format_timestamp, parse_timestamp};
+use crate::trade_whisper::TradeRequest;
+use serde::Serialize;
+
+/// Open trades without progress for this long are marked as timed out
+const TRADE_TIMEOUT_SECS: i64 = 10 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeStatus {
+    Requested,
+    BuyerJoined,
+    Accepted,
+    Cancelled,
+    TimedOut,
+}
+
+impl TradeStatus {
+    fn is_open(self) -> bool {
+        matches!(self, TradeStatus::Requested | TradeStatus::BuyerJoined)
+    }
+}
+
+/// One incoming purchase request followed from whisper to outcome
+#[derive(Debug, Clone, Serialize)]
+pub struct Trade {
+    pub id: u32,
+    pub buyer: String,
+    pub request: TradeRequest,
+    pub status: TradeStatus,
+    pub requested_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub joined_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    // Seconds from the whisper until the buyer joined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_seconds: Option<i64>,
+    // Seconds from the whisper until the trade ended
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<i64>,
+}
+
+impl Trade {
+    fn last_activity(&self) -> &str {
+        self.joined_at.as_deref().unwrap_or(&self.requested_at)
+    }
+
+    fn finish(&mut self, status: TradeStatus, timestamp: &str) {
+        self.status = status;
+        self.completed_at = Some(timestamp.to_string());
+        self.duration_seconds = seconds_between(&self.requested_at, timestamp);
+    }
+}
+
+/// Correlates trade whispers, area joins and trade outcomes by buyer and time
+#[derive(Default)]
+pub struct TradeTracker {
+    trades: Vec<Trade>,
+    // Timestamp of the latest log entry, for expiring trades between entries
+    latest: Option<String>,
+}
+
+impl TradeTracker {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Feeds one log entry and returns every trade whose status changed
+    /// `request` is the buyer and parsed text of an incoming trade whisper
+    pub fn observe(
+        &mut self,
+        full_message: &str,
+        timestamp: &str,
+        locale: &LocalePack,
+        request: Option<(&str, &TradeRequest)>,
+    ) -> Vec<Trade> {
+        self.latest = Some(timestamp.to_string());
+        let mut changed = self.expire(timestamp);
+
+        if let Some((buyer, request)) = request {
+            let trade = Trade {
+                id: self.trades.len() as u32 + 1,
+                buyer: buyer.to_string(),
+                request: request.clone(),
+                status: TradeStatus::Requested,
+                requested_at: timestamp.to_string(),
+                joined_at: None,
+                completed_at: None,
+                wait_seconds: None,
+                duration_seconds: None,
+            };
+            changed.push(trade.clone());
+            self.trades.push(trade);
+            return changed;
+        }
+
+        if let Some((PresenceAction::Joined, name)) = parse_presence(full_message, locale) {
+            for trade in self.trades.iter_mut().filter(|trade| {
+                trade.status == TradeStatus::Requested && trade.buyer == name
+            }) {
+                trade.status = TradeStatus::BuyerJoined;
+                trade.joined_at = Some(timestamp.to_string());
+                trade.wait_seconds = seconds_between(&trade.requested_at, timestamp);
+                changed.push(trade.clone());
+            }
+            return changed;
+        }
+
+        // Only the system line counts, so chat quoting "Trade accepted" can't close a trade
+        let outcome = if is_system_message(full_message, locale.trade_accepted) {
+            TradeStatus::Accepted
+        } else if is_system_message(full_message, locale.trade_cancelled) {
+            TradeStatus::Cancelled
+        } else {
+            return changed;
+        };
+
+        // The outcome line doesn't name the other party, so it goes to the buyer who joined last
+        let buyer = self
+            .trades
+            .iter()
+            .rev()
+            .find(|trade| trade.status == TradeStatus::BuyerJoined)
+            .map(|trade| trade.buyer.clone());
+        if let Some(buyer) = buyer {
+            for trade in self.trades.iter_mut().filter(|trade| {
+                trade.status == TradeStatus::BuyerJoined && trade.buyer == buyer
+            }) {
+                trade.finish(outcome, timestamp);
+                changed.push(trade.clone());
+            }
+        }
+        changed
+    }
+
+    /// All trades, newest first
+    pub fn trades(&self) -> Vec<Trade> {
+        self.trades.iter().rev().cloned().collect()
+    }
+
+    /// Times out idle trades as of `now`, or as of the latest log entry when None
+    pub fn expire_as_of(&mut self, now: Option<&str>) -> Vec<Trade> {
+        match now.map(str::to_string).or_else(|| self.latest.clone()) {
+            Some(now) => self.expire(&now),
+            None => Vec::new(),
+        }
+    }
+
+    /// A timed-out trade ends when its timeout ran out, not when the expiry is noticed
+    fn expire(&mut self, timestamp: &str) -> Vec<Trade> {
+        let Some(now) = parse_timestamp(timestamp) else {
+            return Vec::new();
+        };
+        let mut expired = Vec::new();
+        for trade in self.trades.iter_mut().filter(|trade| trade.status.is_open()) {
+            let deadline = parse_timestamp(trade.last_activity()).map(|at| at + TRADE_TIMEOUT_SECS);
+            if let Some(deadline) = deadline.filter(|deadline| now > *deadline) {
+                trade.finish(TradeStatus::TimedOut, &format_timestamp(deadline));
+                expired.push(trade.clone());
+            }
+        }
+        expired
+    }
+}
+
+fn seconds_between(from: &str, to: &str) -> Option<i64> {
+    Some(parse_timestamp(to)? - parse_timestamp(from)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale;
+    use crate::trade_whisper::parse_trade_request;
+
+    #[test]
+    fn timed_out_trade_ends_when_its_timeout_ran_out() {
+        let request = parse_trade_request("Hi, I'd like to buy your 5 Divine Orb for my 300 Chaos Orb in Standard.")
+            .expect("trade request");
+        let mut tracker = TradeTracker::default();
+        tracker.observe("", "2025/11/04 19:00:00", &locale::ENGLISH, Some(("Kekius", &request)));
+        assert!(tracker.expire_as_of(Some("2025/11/04 19:09:00")).is_empty());
+
+        let expired = tracker.expire_as_of(Some("2025/11/04 21:00:00"));
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].status, TradeStatus::TimedOut);
+        assert_eq!(expired[0].completed_at.as_deref(), Some("2025/11/04 19:10:00"));
+        assert_eq!(expired[0].duration_seconds, Some(TRADE_TIMEOUT_SECS));
+    }
+}
@@ -0,0 +1,153 @@
+use serde::Serialize;
+
+/// Openings the trade site puts in front of a purchase whisper
+const LISTING_PREFIXES: &[&str] = &["Hi, I would like to buy your ", "Hi, I'd like to buy your "];
+
+/// Purchase request generated by the trade site
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeRequest {
+    pub item: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_currency: Option<String>,
+    pub league: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stash_tab: Option<String>,
+    // Cell of the item in the stash tab, counted from the top left
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<u32>,
+    // Anything the buyer typed after the generated text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Parses the text of a whisper into a trade request
+/// Patterns:
+///   "Hi, I would like to buy your X listed for 5 exalted in League (stash tab "T"; position: left 3, top 7) note"
+///   "Hi, I'd like to buy your 5 Divine Orb for my 300 Chaos Orb in League."
+pub fn parse_trade_request(text: &str) -> Option<TradeRequest> {
+    let rest = LISTING_PREFIXES
+        .iter()
+        .find_map(|prefix| text.trim().strip_prefix(prefix))?;
+
+    let (head, stash) = match rest.rfind(" (stash tab \"") {
+        Some(pos) => (&rest[..pos], parse_stash_position(&rest[pos + 13..])),
+        None => (rest.trim_end_matches('.'), None),
+    };
+
+    let (item, price, league) = if let Some((item, tail)) = head.split_once(" listed for ") {
+        let (price, league) = tail.split_once(" in ")?;
+        (item, Some(price), league)
+    } else if let Some((item, tail)) = head.split_once(" for my ") {
+        let (price, league) = tail.rsplit_once(" in ")?;
+        (item, Some(price), league)
+    } else {
+        let (item, league) = head.rsplit_once(" in ")?;
+        (item, None, league)
+    };
+
+    let (price_amount, price_currency) = match price.and_then(|price| price.split_once(' ')) {
+        Some((amount, currency)) => (amount.parse().ok(), Some(currency.trim().to_string())),
+        None => (None, None),
+    };
+
+    let (stash_tab, left, top, note) = match stash {
+        Some(stash) => (Some(stash.tab), stash.left, stash.top, stash.note),
+        None => (None, None, None, None),
+    };
+
+    Some(TradeRequest {
+        item: item.trim().to_string(),
+        price_amount,
+        price_currency,
+        league: league.trim().to_string(),
+        stash_tab,
+        left,
+        top,
+        note,
+    })
+}
+
+struct StashPosition {
+    tab: String,
+    left: Option<u32>,
+    top: Option<u32>,
+    note: Option<String>,
+}
+
+/// Pattern after the opening quote: "Tab"; position: left 3, top 7) note
+fn parse_stash_position(text: &str) -> Option<StashPosition> {
+    let (tab, rest) = text.split_once("\"; position: ")?;
+    let (position, note) = rest.split_once(')')?;
+
+    let mut left = None;
+    let mut top = None;
+    for part in position.split(',') {
+        let part = part.trim();
+        if let Some(value) = part.strip_prefix("left ") {
+            left = value.parse().ok();
+        } else if let Some(value) = part.strip_prefix("top ") {
+            top = value.parse().ok();
+        }
+    }
+
+    let note = note.trim();
+    Some(StashPosition {
+        tab: tab.to_string(),
+        left,
+        top,
+        note: Some(note.to_string()).filter(|note| !note.is_empty()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_listing_with_stash_position() {
+        let request = parse_trade_request(
+            "Hi, I would like to buy your Ritual Precursor Tablet listed for 5 exalted in Rise of the Abyssal (stash tab \"~price 5 exalted\"; position: left 3, top 7)",
+        )
+        .expect("trade request");
+
+        assert_eq!(request.item, "Ritual Precursor Tablet");
+        assert_eq!(request.price_amount, Some(5.0));
+        assert_eq!(request.price_currency.as_deref(), Some("exalted"));
+        assert_eq!(request.league, "Rise of the Abyssal");
+        assert_eq!(request.stash_tab.as_deref(), Some("~price 5 exalted"));
+        assert_eq!((request.left, request.top), (Some(3), Some(7)));
+        assert_eq!(request.note, None);
+    }
+
+    #[test]
+    fn parses_currency_exchange_for_my() {
+        let request = parse_trade_request("Hi, I'd like to buy your 5 Divine Orb for my 300 Chaos Orb in Standard.")
+            .expect("trade request");
+
+        assert_eq!(request.item, "5 Divine Orb");
+        assert_eq!(request.price_amount, Some(300.0));
+        assert_eq!(request.price_currency.as_deref(), Some("Chaos Orb"));
+        assert_eq!(request.league, "Standard");
+        assert_eq!(request.stash_tab, None);
+    }
+
+    #[test]
+    fn keeps_buyer_note_after_stash_position() {
+        let request = parse_trade_request(
+            "Hi, I would like to buy your Grand Spectrum listed for 1 divine in Standard (stash tab \"sale\"; position: left 12, top 1) can you do 0.8?",
+        )
+        .expect("trade request");
+
+        assert_eq!(request.note.as_deref(), Some("can you do 0.8?"));
+        assert_eq!((request.left, request.top), (Some(12), Some(1)));
+    }
+
+    #[test]
+    fn ignores_ordinary_whispers() {
+        assert!(parse_trade_request("Hi, is this still available?").is_none());
+    }
+}
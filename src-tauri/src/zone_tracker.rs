@@ -0,0 +1,106 @@
+use crate::log_categorizer::strip_header;
+use serde::Serialize;
+
+/// The player entered a new area
+#[derive(Debug, Clone, Serialize)]
+pub struct ZoneChange {
+    pub area_name: String,
+    // Internal area id from the "Generating level" line, e.g. "G1_4_3"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_level: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    pub timestamp: String,
+}
+
+/// Details from a "Generating level" line, waiting for the matching scene change
+struct GeneratedArea {
+    level: u32,
+    code: String,
+    seed: u64,
+}
+
+/// Follows area transitions through the log and remembers the current zone
+#[derive(Default)]
+pub struct ZoneTracker {
+    pending: Option<GeneratedArea>,
+    current: Option<ZoneChange>,
+}
+
+impl ZoneTracker {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn current(&self) -> Option<&ZoneChange> {
+        self.current.as_ref()
+    }
+
+    /// Feeds one log entry; returns the zone change it completes, if any
+    pub fn observe(&mut self, full_message: &str, timestamp: &str) -> Option<ZoneChange> {
+        let body = strip_header(full_message);
+
+        if let Some(area) = parse_generating_level(body) {
+            self.pending = Some(area);
+            return None;
+        }
+
+        let area_name = parse_scene_source(body)?;
+        let generated = self.pending.take();
+
+        // The client re-sets the scene source without a new instance, e.g. after a loading screen
+        if generated.is_none()
+            && self.current.as_ref().map(|zone| zone.area_name.as_str()) == Some(area_name)
+        {
+            return None;
+        }
+
+        let change = ZoneChange {
+            area_name: area_name.to_string(),
+            area_code: generated.as_ref().map(|area| area.code.clone()),
+            area_level: generated.as_ref().map(|area| area.level),
+            seed: generated.as_ref().map(|area| area.seed),
+            timestamp: timestamp.to_string(),
+        };
+        self.current = Some(change.clone());
+        Some(change)
+    }
+}
+
+/// Pattern: "Generating level 45 area "G1_4_3" with seed 2842339022"
+fn parse_generating_level(body: &str) -> Option<GeneratedArea> {
+    let rest = body.strip_prefix("Generating level ")?;
+    let level_end = rest.find(' ')?;
+    let level = rest[..level_end].parse().ok()?;
+
+    let rest = rest[level_end..].strip_prefix(" area \"")?;
+    let code_end = rest.find('"')?;
+    let code = rest[..code_end].to_string();
+
+    let seed_str: String = rest[code_end + 1..]
+        .trim_start()
+        .strip_prefix("with seed ")?
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+
+    Some(GeneratedArea {
+        level,
+        code,
+        seed: seed_str.parse().ok()?,
+    })
+}
+
+/// Pattern: "[SCENE] Set Source [Stones of Serle]"
+fn parse_scene_source(body: &str) -> Option<&str> {
+    let rest = body.strip_prefix("[SCENE] Set Source [")?;
+    let name = &rest[..rest.rfind(']')?];
+
+    // Loading screens set an empty source first
+    if name.is_empty() || name == "(null)" {
+        return None;
+    }
+    Some(name)
+}
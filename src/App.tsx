@@ -6,6 +6,7 @@ import { getVersion } from "@tauri-apps/api/app";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import LogViewer from "./components/LogViewer";
 import FilterPanel from "./components/FilterPanel";
+import ItemFilterHistory from "./components/ItemFilterHistory";
 
 interface LogEvent {
   timestamp: string;
@@ -45,6 +46,7 @@ function App() {
   const [lastCheckResult, setLastCheckResult] = useState<
     "none" | "up-to-date" | "available" | "error"
   >("none");
+  const [showFilterHistory, setShowFilterHistory] = useState(false);
   const processedLogIds = useRef(new Set<string>());
 
   useEffect(() => {
@@ -355,6 +357,16 @@ function App() {
             />
             <span>Auto-start watching</span>
           </label>
+          <button
+            onClick={() => setShowFilterHistory((prev) => !prev)}
+            className={`ml-auto transition-colors ${
+              showFilterHistory
+                ? "text-poe-gold"
+                : "text-poe-text-muted hover:text-poe-gold"
+            }`}
+          >
+            Item filter history
+          </button>
         </div>
 
         {error && (
@@ -364,6 +376,12 @@ function App() {
         )}
       </div>
 
+      {showFilterHistory && (
+        <div className="border-b border-poe-border shrink-0">
+          <ItemFilterHistory refreshKey={logs.length} />
+        </div>
+      )}
+
       {/* Filter Panel */}
       <div className="bg-poe-dark border-b border-poe-border shrink-0">
         <FilterPanel
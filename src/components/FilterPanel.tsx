@@ -1,4 +1,14 @@
+import { useEffect, useState } from "react";
 import type { Dispatch, SetStateAction } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
+interface CategoryInfo {
+  name: string;
+  priority: number;
+  description: string;
+  color: string;
+  group: "gameplay" | "system";
+}
 
 interface Props {
   filters: string[];
@@ -13,24 +23,24 @@ export default function FilterPanel({
   searchTerm,
   setSearchTerm,
 }: Props) {
-  const gameplayCategories = [
-    "Death",
-    "Level Up",
-    "Skill",
-    "Trade",
-    "Dialogue",
-    "Guild",
-    "Gameplay",
-  ];
-
-  const systemCategories = [
-    "Network",
-    "Item Filter",
-    "Graphics",
-    "Engine",
-    "Audio",
-    "Warnings",
-  ];
+  const [categories, setCategories] = useState<CategoryInfo[]>([]);
+
+  useEffect(() => {
+    invoke<CategoryInfo[]>("list_categories")
+      .then(setCategories)
+      .catch((err) => console.error("Failed to load categories:", err));
+  }, []);
+
+  const gameplayCategories = categories
+    .filter((cat) => cat.group === "gameplay")
+    .map((cat) => cat.name);
+
+  const systemCategories = categories
+    .filter((cat) => cat.group === "system")
+    .map((cat) => cat.name);
+
+  const describe = (name: string) =>
+    categories.find((cat) => cat.name === name)?.description;
 
   const toggleFilter = (category: string) => {
     if (filters.includes(category)) {
@@ -89,6 +99,7 @@ export default function FilterPanel({
             <button
               key={cat}
               onClick={() => toggleFilter(cat)}
+              title={describe(cat)}
               className={`px-2 py-1 rounded text-xs transition-colors ${
                 filters.includes(cat)
                   ? "bg-poe-muted hover:bg-poe-border text-poe-gold ring-1 ring-poe-gold-dim/50"
@@ -106,6 +117,7 @@ export default function FilterPanel({
             <button
               key={cat}
               onClick={() => toggleFilter(cat)}
+              title={describe(cat)}
               className={`px-2 py-1 rounded text-xs transition-colors ${
                 filters.includes(cat)
                   ? "bg-poe-muted hover:bg-poe-border text-poe-text-dim ring-1 ring-poe-border"
@@ -0,0 +1,71 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
+interface FilterHistoryEntry {
+  timestamp: string;
+  filter_id: string;
+  outcome: "pending" | "completed" | "incomplete";
+  hash?: string;
+  previous_filter_id?: string;
+  previous_hash?: string;
+}
+
+interface Props {
+  // Changes whenever new log entries arrive, so the history is re-fetched
+  refreshKey: number;
+}
+
+export default function ItemFilterHistory({ refreshKey }: Props) {
+  const [entries, setEntries] = useState<FilterHistoryEntry[]>([]);
+
+  useEffect(() => {
+    invoke<FilterHistoryEntry[]>("get_item_filter_history")
+      .then(setEntries)
+      .catch((err) => console.error("Failed to load item filter history:", err));
+  }, [refreshKey]);
+
+  const shortHash = (hash?: string) => (hash ? hash.substring(0, 8) : "—");
+
+  return (
+    <div className="p-3 bg-poe-darker text-xs">
+      <h3 className="text-sm font-medium text-poe-gold mb-2">
+        Item Filter History
+      </h3>
+      {entries.length === 0 ? (
+        <div className="text-poe-text-muted">No filter downloads seen yet</div>
+      ) : (
+        <div className="max-h-40 overflow-y-auto space-y-1">
+          {entries.map((entry, index) => (
+            <div
+              key={`${entry.timestamp}-${index}`}
+              className="flex items-center gap-3 font-mono"
+            >
+              <span className="text-poe-text-muted">{entry.timestamp}</span>
+              <span className="text-gray-300">
+                {entry.filter_id}{" "}
+                <span className="text-poe-text-muted">
+                  {entry.hash &&
+                    `${shortHash(entry.previous_hash)} → ${shortHash(entry.hash)}`}
+                  {entry.outcome === "pending" && "downloading…"}
+                </span>
+                {entry.outcome === "incomplete" && (
+                  <span className="text-red-400">
+                    {" "}
+                    incomplete, no hash reported
+                  </span>
+                )}
+                {entry.previous_filter_id &&
+                  entry.previous_filter_id !== entry.filter_id && (
+                    <span className="text-poe-text-muted">
+                      {" "}
+                      (was {entry.previous_filter_id})
+                    </span>
+                  )}
+              </span>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
@@ -33,6 +33,7 @@ export default function LogViewer({ logs, filters, searchTerm }: Props) {
       "Item Filter": "text-pink-400",
       Trade: "text-poe-gold font-medium",
       Gameplay: "text-orange-400",
+      Status: "text-teal-400",
       Network: "text-poe-text-dim",
       Downloads: "text-cyan-400",
       Graphics: "text-blue-400",
@@ -58,6 +59,14 @@ export default function LogViewer({ logs, filters, searchTerm }: Props) {
       }
     }
 
+    if (log.category === "Trade" && message.includes("@To")) {
+      const toMatch = message.match(/@To ([^:]+): (.+)/);
+      if (toMatch) {
+        const [, recipient, tradeMessage] = toMatch;
+        return `↗ ${recipient}: ${tradeMessage}`;
+      }
+    }
+
     if (log.category === "Skill" && message.includes("have received")) {
       message = message.replace(/have received ([^.]+)/, "gained $1");
     }
@@ -116,6 +125,7 @@ export default function LogViewer({ logs, filters, searchTerm }: Props) {
       Guild: "🏛️",
       "Item Filter": "🔍",
       Gameplay: "🎮",
+      Status: "👥",
       Network: "🌐",
       Downloads: "📥",
       Graphics: "🎨",
@@ -194,6 +204,8 @@ export default function LogViewer({ logs, filters, searchTerm }: Props) {
                         ? "bg-poe-muted text-poe-gold"
                         : log.category === "Gameplay"
                         ? "bg-orange-950/60 text-orange-400"
+                        : log.category === "Status"
+                        ? "bg-teal-950/60 text-teal-400"
                         : log.category === "Network"
                         ? "bg-poe-muted text-poe-text-dim"
                         : log.category === "Downloads"